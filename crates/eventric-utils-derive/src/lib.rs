@@ -0,0 +1,91 @@
+//! Derive macros for the `eventric-utils` crate, most notably a derive macro
+//! for the `Validate` trait of the `validation` module. These macros are
+//! re-exported by `eventric-utils` when the `derive` feature is enabled, and
+//! should generally be used through that re-export.
+
+#![deny(clippy::missing_errors_doc)]
+#![deny(clippy::missing_panics_doc)]
+#![deny(clippy::missing_safety_doc)]
+#![deny(missing_docs)]
+#![deny(unsafe_code)]
+
+mod validate;
+
+use proc_macro::TokenStream;
+use syn::{
+    DeriveInput,
+    parse_macro_input,
+};
+
+// =================================================================================================
+// Eventric Utilities Derive
+// =================================================================================================
+
+/// Derives implementations of the `Validate` and `ValidateNested` traits for a
+/// struct, tuple struct, or enum, validating each field annotated with a
+/// `#[validate(...)]` attribute using the validators given, and naming any
+/// failure after the field (or the field index, for tuple fields).
+///
+/// Validators are given as a comma-separated list, in the order in which they
+/// should be applied:
+///
+/// - a `snake_case` name refers to the unit validator of the corresponding
+///   `UpperCamelCase` name in the `validation` module, so `not_empty` refers to
+///   `NotEmpty` and `no_white_space` refers to `NoWhiteSpace`,
+/// - a `snake_case` name with arguments, such as `max_length(64)`, refers to
+///   the `new` constructor of the corresponding validator type, so that
+///   `max_length(64)` refers to `MaxLength::new(64)`, except that
+///   `matches(pattern)` builds its `Matches` validator once, in a static, on
+///   first use (panicking if the pattern is not a valid regular expression),
+/// - a multi-segment path, such as `crate::IsPositive`, refers to a unit
+///   validator outside of the `validation` module,
+/// - `custom(expression)` uses the given expression as the validator, which may
+///   refer to `self` (for example to apply a validator conditionally using
+///   `When`, based on the value of another field),
+/// - `nested` validates the field using its own `ValidateNested` implementation
+///   (after any other validators), prefixing the paths of any resulting
+///   failures with the field name.
+///
+/// Advisory failures (such as those of validators wrapped in `Advisory`) do not
+/// cause validation to fail, and are collected, along with those of `nested`
+/// fields, when validating using `ValidateNested::validate_nested_reported`.
+///
+/// The error type of the implementation defaults to the `validation` module
+/// `Error` type, and may be set using a container attribute of the form
+/// `#[validate(error = MyError)]`.
+///
+/// Struct-level rules, which see the whole value and may relate several fields,
+/// may be given using container attributes of the form
+/// `#[validate(rule(expression))]`, where the expression is a `Rule` (such as
+/// a method of the form `fn(&self) -> Result<(), Error>`). Rules are checked
+/// after all fields have been validated, in the same mode.
+///
+/// An implementation of the `ValidateSchema` trait (requiring the `schema`
+/// feature of `eventric-utils`) may also be derived for a struct with named
+/// fields or a single unnamed field, using the container attribute
+/// `#[validate(schema)]`. The schema describes each validated field using the
+/// JSON Schema keywords of its validators (which must implement `Describe`),
+/// starting from the schema of the field type where the field is `nested`.
+/// Validators given as `custom(expression)` and struct-level rules are not
+/// described, as they may depend on the value being validated.
+///
+/// ```ignore
+/// #[derive(Validate)]
+/// #[validate(rule(Event::tags_required_when_published))]
+/// struct Event {
+///     #[validate(not_empty, no_white_space)]
+///     name: String,
+///     #[validate(custom(IsPositive))]
+///     version: i32,
+///     #[validate(nested)]
+///     tags: Vec<Tag>,
+/// }
+/// ```
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    validate::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,446 @@
+use proc_macro2::{
+    Span,
+    TokenStream,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    Attribute,
+    Data,
+    DataEnum,
+    DeriveInput,
+    Expr,
+    Fields,
+    Ident,
+    Path,
+    Token,
+    Type,
+    ext::IdentExt as _,
+    parenthesized,
+    punctuated::Punctuated,
+};
+
+// =================================================================================================
+// Validate
+// =================================================================================================
+
+pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let container = parse_container(&input.attrs)?;
+    let validations = match &input.data {
+        Data::Enum(data) => derive_enum(data)?,
+        Data::Struct(data) => derive_struct(&data.fields)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Validate cannot be derived for unions",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let error = container.error.map_or_else(
+        || quote!(::eventric_utils::validation::Error),
+        |error| quote!(#error),
+    );
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let schema = if container.schema {
+        let body = derive_schema(input)?;
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::eventric_utils::validation::ValidateSchema for #name #type_generics #where_clause {
+                fn schema() -> ::eventric_utils::validation::Schema {
+                    #body
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let collector = collector();
+    let mode = mode();
+    let report = report();
+    let rules = rules(&container.rules);
+    let body = if validations.is_some() || rules.is_some() {
+        quote! {
+            let mut #collector = ::eventric_utils::validation::Collector::new(#mode);
+
+            #validations
+            #rules
+
+            #collector.finish()
+        }
+    } else {
+        quote! {
+            let _ = (#mode, #report);
+
+            ::core::result::Result::Ok(())
+        }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::eventric_utils::validation::ValidateNested for #name #type_generics #where_clause {
+            fn validate_nested(
+                &self,
+                #mode: ::eventric_utils::validation::Mode,
+            ) -> ::core::result::Result<(), ::eventric_utils::validation::Error> {
+                ::eventric_utils::validation::ValidateNested::validate_nested_reported(
+                    self,
+                    #mode,
+                    &mut ::eventric_utils::validation::Report::new(),
+                )
+            }
+
+            fn validate_nested_reported(
+                &self,
+                #mode: ::eventric_utils::validation::Mode,
+                #report: &mut ::eventric_utils::validation::Report,
+            ) -> ::core::result::Result<(), ::eventric_utils::validation::Error> {
+                #body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::eventric_utils::validation::Validate for #name #type_generics #where_clause {
+            type Err = #error;
+
+            fn validate(self) -> ::core::result::Result<Self, Self::Err> {
+                ::eventric_utils::validation::ValidateNested::validate_nested(
+                    &self,
+                    ::eventric_utils::validation::Mode::FailFast,
+                )?;
+
+                ::core::result::Result::Ok(self)
+            }
+        }
+
+        #schema
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Bodies
+
+fn derive_enum(data: &DataEnum) -> syn::Result<Option<TokenStream>> {
+    let mut arms = Vec::new();
+    let mut any = false;
+
+    for variant in &data.variants {
+        let variant_name = &variant.ident;
+        let mut bindings = Vec::new();
+        let mut validations = Vec::new();
+
+        for (i, field) in variant.fields.iter().enumerate() {
+            let Some(attrs) = parse_field(&field.attrs)? else {
+                continue;
+            };
+
+            let binding = format_ident!("__field_{i}");
+            let member = field.ident.as_ref().map_or_else(
+                || {
+                    let index = syn::Index::from(i);
+                    quote!(#index)
+                },
+                |ident| quote!(#ident),
+            );
+
+            validations.push(validation(
+                &quote!(#binding),
+                &field_name(field.ident.as_ref(), i),
+                &field.ty,
+                &attrs,
+            ));
+            bindings.push(quote!(#member: #binding));
+        }
+
+        any |= !validations.is_empty();
+        arms.push(quote! {
+            Self::#variant_name { #(#bindings,)* .. } => {
+                #(#validations)*
+            }
+        });
+    }
+
+    Ok(any.then(|| {
+        quote! {
+            match self {
+                #(#arms)*
+            }
+        }
+    }))
+}
+
+fn derive_struct(fields: &Fields) -> syn::Result<Option<TokenStream>> {
+    let mut validations = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let Some(attrs) = parse_field(&field.attrs)? else {
+            continue;
+        };
+
+        let value = field.ident.as_ref().map_or_else(
+            || {
+                let index = syn::Index::from(i);
+                quote!(&self.#index)
+            },
+            |ident| quote!(&self.#ident),
+        );
+
+        validations.push(validation(
+            &value,
+            &field_name(field.ident.as_ref(), i),
+            &field.ty,
+            &attrs,
+        ));
+    }
+
+    Ok((!validations.is_empty()).then(|| quote!(#(#validations)*)))
+}
+
+fn derive_schema(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "schema can only be derived for structs",
+            ));
+        }
+    };
+
+    match fields {
+        Fields::Named(fields) => {
+            let mut properties = Vec::new();
+
+            for field in &fields.named {
+                let Some(attrs) = parse_field(&field.attrs)? else {
+                    continue;
+                };
+
+                let name = field_name(field.ident.as_ref(), 0);
+                let schema = describe(&field.ty, &attrs);
+
+                properties.push(quote!((#name, #schema)));
+            }
+
+            Ok(quote! {
+                ::eventric_utils::validation::describe_object([#(#properties),*])
+            })
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let field = &fields.unnamed[0];
+            let attrs = parse_field(&field.attrs)?.unwrap_or(FieldAttrs {
+                described: Vec::new(),
+                nested: false,
+                validators: Vec::new(),
+            });
+
+            Ok(describe(&field.ty, &attrs))
+        }
+        _ => Err(syn::Error::new_spanned(
+            input,
+            "schema can only be derived for structs with named fields or a single unnamed field",
+        )),
+    }
+}
+
+fn describe(ty: &Type, attrs: &FieldAttrs) -> TokenStream {
+    let described = &attrs.described;
+
+    if attrs.nested {
+        quote!(::eventric_utils::validation::describe_nested::<#ty>(&[#(&#described),*]))
+    } else {
+        quote!(::eventric_utils::validation::describe(&[#(&#described),*]))
+    }
+}
+
+fn validation(value: &TokenStream, name: &str, ty: &Type, attrs: &FieldAttrs) -> TokenStream {
+    let collector = collector();
+    let mode = mode();
+    let report = report();
+    let validators = &attrs.validators;
+
+    let validate = (!validators.is_empty()).then(|| {
+        quote! {
+            #collector.collect(#mode.validate_reported::<#ty, _>(#value, #name, &[#(&#validators),*], #report))?;
+        }
+    });
+
+    let nested = attrs.nested.then(|| {
+        quote! {
+            #collector.collect(::eventric_utils::validation::validate_nested_reported(#value, #name, #mode, #report))?;
+        }
+    });
+
+    quote!(#validate #nested)
+}
+
+fn rules(rules: &[Expr]) -> Option<TokenStream> {
+    let collector = collector();
+    let mode = mode();
+
+    (!rules.is_empty()).then(|| {
+        quote! {
+            #collector.collect(::eventric_utils::validation::validate_rules(self, &[#(&#rules),*], #mode))?;
+        }
+    })
+}
+
+fn field_name(ident: Option<&Ident>, index: usize) -> String {
+    ident.map_or_else(|| index.to_string(), |ident| ident.unraw().to_string())
+}
+
+fn collector() -> Ident {
+    Ident::new("collector", Span::mixed_site())
+}
+
+fn mode() -> Ident {
+    Ident::new("mode", Span::mixed_site())
+}
+
+fn report() -> Ident {
+    Ident::new("report", Span::mixed_site())
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Attributes
+
+struct ContainerAttrs {
+    error: Option<Path>,
+    rules: Vec<Expr>,
+    schema: bool,
+}
+
+struct FieldAttrs {
+    described: Vec<TokenStream>,
+    nested: bool,
+    validators: Vec<TokenStream>,
+}
+
+fn parse_container(attrs: &[Attribute]) -> syn::Result<ContainerAttrs> {
+    let mut container = ContainerAttrs {
+        error: None,
+        rules: Vec::new(),
+        schema: false,
+    };
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("error") {
+                container.error = Some(meta.value()?.parse()?);
+
+                Ok(())
+            } else if meta.path.is_ident("rule") {
+                let content;
+
+                parenthesized!(content in meta.input);
+                container.rules.push(content.parse()?);
+
+                Ok(())
+            } else if meta.path.is_ident("schema") {
+                container.schema = true;
+
+                Ok(())
+            } else {
+                Err(meta.error("unsupported validate container attribute"))
+            }
+        })?;
+    }
+
+    Ok(container)
+}
+
+fn parse_field(attrs: &[Attribute]) -> syn::Result<Option<FieldAttrs>> {
+    let mut field = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        let field = field.get_or_insert_with(|| FieldAttrs {
+            described: Vec::new(),
+            nested: false,
+            validators: Vec::new(),
+        });
+
+        attr.parse_nested_meta(|meta| {
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+
+                parenthesized!(content in meta.input);
+
+                if meta.path.is_ident("custom") {
+                    let expr: Expr = content.parse()?;
+
+                    field.validators.push(quote!(#expr));
+                } else if meta.path.is_ident("matches") {
+                    let ty = validator_path(&meta.path)?;
+                    let args = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+                    let validator = lazy_validator(&ty, &args);
+
+                    field.described.push(validator.clone());
+                    field.validators.push(validator);
+                } else {
+                    let ty = validator_path(&meta.path)?;
+                    let args = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+
+                    field.described.push(quote!(#ty::new(#args)));
+                    field.validators.push(quote!(#ty::new(#args)));
+                }
+            } else if meta.path.is_ident("nested") {
+                field.nested = true;
+            } else {
+                let ty = validator_path(&meta.path)?;
+
+                field.described.push(quote!(#ty));
+                field.validators.push(quote!(#ty));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(field)
+}
+
+fn validator_path(path: &Path) -> syn::Result<TokenStream> {
+    match path.get_ident() {
+        Some(ident) => {
+            let ident = Ident::new(&upper_camel_case(&ident.to_string()), ident.span());
+
+            Ok(quote!(::eventric_utils::validation::#ident))
+        }
+        None if path.segments.len() > 1 => Ok(quote!(#path)),
+        None => Err(syn::Error::new_spanned(path, "unsupported validator")),
+    }
+}
+
+fn lazy_validator(ty: &TokenStream, args: &Punctuated<Expr, Token![,]>) -> TokenStream {
+    // The validator is built once, on first use, and dereferenced so that it is
+    // borrowed in the same way as validators built in place.
+
+    quote! {
+        *{
+            static VALIDATOR: ::std::sync::LazyLock<#ty> = ::std::sync::LazyLock::new(|| {
+                #ty::new(#args).expect("invalid validator arguments")
+            });
+
+            &*VALIDATOR
+        }
+    }
+}
+
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect()
+}
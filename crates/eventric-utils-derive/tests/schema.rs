@@ -0,0 +1,173 @@
+//! Tests for the `ValidateSchema` implementation of the `Validate` derive
+//! macro.
+
+use eventric_utils::validation::{
+    Describe,
+    Failure,
+    Schema,
+    Validate,
+    ValidateSchema,
+    Validator,
+    schema_document,
+};
+use serde_json::json;
+
+// =================================================================================================
+// Schema
+// =================================================================================================
+
+// Test Validators
+
+struct IsPositive;
+
+impl Validator<i32> for IsPositive {
+    fn validate(&self, value: &i32) -> Option<Failure> {
+        (*value <= 0).then(|| Failure::new("not_positive", "not positive"))
+    }
+}
+
+impl Describe for IsPositive {
+    fn describe(&self, schema: &mut Schema) {
+        schema.insert(String::from("exclusiveMinimum"), json!(0));
+    }
+}
+
+// Test Types
+
+#[derive(Debug, Validate)]
+#[validate(schema)]
+struct Tag {
+    #[validate(not_empty, max_length(16))]
+    name: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(schema, rule(|_: &Event| Ok(())))]
+struct Event {
+    #[validate(not_empty, no_white_space)]
+    name: String,
+    #[validate(crate::IsPositive, custom(IsPositive))]
+    version: i32,
+    #[validate(max_items(4), nested)]
+    tags: Vec<Tag>,
+    description: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(schema)]
+struct Identifier(#[validate(min_length(1), max_length(64))] String);
+
+#[derive(Debug, Validate)]
+#[validate(schema)]
+struct EventType(#[validate(matches(r"[a-z]+(\.[a-z]+)*"))] String);
+
+#[derive(Debug, Validate)]
+#[validate(schema)]
+struct Unvalidated(String);
+
+// Named Fields
+
+#[test]
+fn schema_named_fields() {
+    assert_eq!(
+        serde_json::Value::Object(Event::schema()),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "minLength": 1,
+                    "minItems": 1,
+                    "minProperties": 1,
+                    "pattern": r"^\S*$"
+                },
+                "version": {
+                    "exclusiveMinimum": 0
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "name": {
+                                "minLength": 1,
+                                "minItems": 1,
+                                "minProperties": 1,
+                                "maxLength": 16,
+                                "maxItems": 16,
+                                "maxProperties": 16
+                            }
+                        }
+                    },
+                    "maxItems": 4,
+                    "maxProperties": 4
+                }
+            }
+        })
+    );
+}
+
+// Unnamed Fields
+
+#[test]
+fn schema_newtype() {
+    assert_eq!(
+        serde_json::Value::Object(Identifier::schema()),
+        json!({
+            "minLength": 1,
+            "minItems": 1,
+            "minProperties": 1,
+            "maxLength": 64,
+            "maxItems": 64,
+            "maxProperties": 64
+        })
+    );
+    assert_eq!(
+        serde_json::Value::Object(EventType::schema()),
+        json!({ "pattern": r"^(?:[a-z]+(\.[a-z]+)*)$" })
+    );
+    assert_eq!(serde_json::Value::Object(Unvalidated::schema()), json!({}));
+}
+
+// Validation
+
+#[test]
+fn schema_validation_unchanged() {
+    let event = Event {
+        name: String::from("created"),
+        version: 1,
+        tags: vec![Tag {
+            name: String::from("a"),
+        }],
+        description: String::from("an event"),
+    }
+    .validate()
+    .unwrap();
+
+    assert_eq!(event.description, "an event");
+    assert_eq!(Unvalidated(String::from(" ")).validate().unwrap().0, " ");
+}
+
+// Document
+
+#[test]
+fn schema_document_derived() {
+    let document = schema_document::<Tag>();
+
+    assert_eq!(
+        document,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "name": {
+                    "minLength": 1,
+                    "minItems": 1,
+                    "minProperties": 1,
+                    "maxLength": 16,
+                    "maxItems": 16,
+                    "maxProperties": 16
+                }
+            }
+        })
+    );
+}
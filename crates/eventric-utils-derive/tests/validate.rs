@@ -0,0 +1,513 @@
+//! Tests for the `Validate` derive macro.
+
+use std::collections::BTreeMap;
+
+use assertables::{
+    assert_err,
+    assert_ok,
+};
+use eventric_utils::validation::{
+    Advisory,
+    Error,
+    Failure,
+    Fields,
+    Mode,
+    NoWhiteSpace,
+    Report,
+    Severity,
+    Validate,
+    ValidateNested as _,
+    Validator,
+    When,
+};
+use thiserror::Error;
+
+// =================================================================================================
+// Validate
+// =================================================================================================
+
+// Test Validators
+
+struct IsPositive;
+
+impl Validator<i32> for IsPositive {
+    fn validate(&self, value: &i32) -> Option<Failure> {
+        (*value <= 0).then(|| Failure::new("not_positive", "not positive"))
+    }
+}
+
+struct LessThan(i32);
+
+impl LessThan {
+    fn new(max: i32) -> Self {
+        Self(max)
+    }
+}
+
+impl Validator<i32> for LessThan {
+    fn validate(&self, value: &i32) -> Option<Failure> {
+        (*value >= self.0).then(|| Failure::new("not_less_than", "not less than"))
+    }
+}
+
+// Test Types
+
+#[derive(Debug, Validate)]
+struct Event {
+    #[validate(not_empty, no_white_space)]
+    name: String,
+    #[validate(crate::IsPositive)]
+    version: i32,
+    #[validate(no_control_characters)]
+    r#type: String,
+    description: String,
+}
+
+#[derive(Debug, Validate)]
+struct Tag(
+    #[validate(not_empty)] String,
+    #[validate(custom(LessThan(10)))] i32,
+);
+
+#[derive(Debug, Validate)]
+#[validate(error = CustomError)]
+struct Stream {
+    #[validate(not_empty)]
+    id: String,
+}
+
+#[derive(Debug, Validate)]
+enum Command {
+    Create {
+        #[validate(not_empty, no_preceding_white_space)]
+        name: String,
+    },
+    Update(
+        #[validate(custom(LessThan::new(100)))] i32,
+        #[validate(no_white_space)] String,
+    ),
+    Delete,
+}
+
+#[derive(Debug, Validate)]
+struct Batch {
+    #[validate(not_empty, nested)]
+    events: Vec<Event>,
+    #[validate(nested)]
+    parent: Option<Box<Batch>>,
+    #[validate(nested)]
+    commands: BTreeMap<String, Command>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(rule(Window::end_after_start))]
+#[validate(rule(Fields::new(&["end"], |window: &Window| window.end < 100, "must be below 100")))]
+struct Window {
+    #[validate(custom(LessThan::new(50)))]
+    start: i32,
+    end: i32,
+}
+
+impl Window {
+    fn end_after_start(&self) -> Result<(), Error> {
+        if self.end > self.start {
+            Ok(())
+        } else {
+            Err(Error::InvalidAll(vec![
+                Failure::new("order", "must be before end").with_path("start"),
+                Failure::new("order", "must be after start").with_path("end"),
+            ]))
+        }
+    }
+}
+
+#[derive(Debug, Validate)]
+struct Label(#[validate(custom(Advisory::warning(NoWhiteSpace)))] String);
+
+#[derive(Debug, Validate)]
+struct Labelled {
+    #[validate(not_empty)]
+    name: String,
+    #[validate(nested)]
+    labels: Vec<Label>,
+}
+
+#[derive(Debug, Validate)]
+struct EventType(#[validate(not_empty, matches(r"[a-z]+(\.[a-z]+)*"))] String);
+
+#[derive(Debug, Eq, PartialEq)]
+enum Kind {
+    Identifier,
+    Label,
+}
+
+#[derive(Debug, Validate)]
+struct Name {
+    kind: Kind,
+    #[validate(not_empty, custom(When::new(self.kind == Kind::Identifier, NoWhiteSpace)))]
+    value: String,
+}
+
+#[derive(Debug, Error)]
+enum CustomError {
+    #[error(transparent)]
+    Validation(#[from] Error),
+}
+
+// Test Helpers
+
+fn invalid(code: &'static str, message: &'static str, path: &str) -> Error {
+    Error::Invalid(Failure::new(code, message).with_path(path))
+}
+
+// Structs
+
+fn event(name: &str, version: i32, r#type: &str) -> Event {
+    Event {
+        name: name.to_owned(),
+        version,
+        r#type: r#type.to_owned(),
+        description: String::from(" unvalidated "),
+    }
+}
+
+#[test]
+fn struct_valid() {
+    assert_ok!(event("created", 1, "event").validate());
+}
+
+#[test]
+fn struct_invalid_first_validator() {
+    assert_eq!(
+        event("", 1, "event").validate().unwrap_err(),
+        invalid("empty", "empty", "name")
+    );
+}
+
+#[test]
+fn struct_invalid_second_validator() {
+    assert_eq!(
+        event("was created", 1, "event").validate().unwrap_err(),
+        invalid("whitespace", "whitespace", "name")
+    );
+}
+
+#[test]
+fn struct_invalid_path_validator() {
+    assert_eq!(
+        event("created", 0, "event").validate().unwrap_err(),
+        invalid("not_positive", "not positive", "version")
+    );
+}
+
+#[test]
+fn struct_invalid_raw_field_name() {
+    assert_eq!(
+        event("created", 1, "ev\nent").validate().unwrap_err(),
+        invalid("control_characters", "control characters", "type")
+    );
+}
+
+#[test]
+fn struct_invalid_field_order() {
+    assert_eq!(
+        event("", 0, "ev\nent").validate().unwrap_err(),
+        invalid("empty", "empty", "name")
+    );
+}
+
+#[test]
+fn struct_returns_self() {
+    let event = assert_ok!(event("created", 1, "event").validate());
+
+    assert_eq!(event.name, "created");
+    assert_eq!(event.description, " unvalidated ");
+}
+
+// Tuple Structs
+
+#[test]
+fn tuple_struct_valid() {
+    assert_ok!(Tag(String::from("tag"), 5).validate());
+}
+
+#[test]
+fn tuple_struct_invalid() {
+    assert_eq!(
+        Tag(String::new(), 5).validate().unwrap_err(),
+        invalid("empty", "empty", "0")
+    );
+    assert_eq!(
+        Tag(String::from("tag"), 10).validate().unwrap_err(),
+        invalid("not_less_than", "not less than", "1")
+    );
+}
+
+// Custom Errors
+
+#[test]
+fn custom_error_valid() {
+    assert_ok!(
+        Stream {
+            id: String::from("stream")
+        }
+        .validate()
+    );
+}
+
+#[test]
+fn custom_error_invalid() {
+    let error = assert_err!(Stream { id: String::new() }.validate());
+
+    assert!(
+        matches!(error, CustomError::Validation(error) if error == invalid("empty", "empty", "id"))
+    );
+}
+
+// Enums
+
+#[test]
+fn enum_valid() {
+    assert_ok!(
+        Command::Create {
+            name: String::from("name")
+        }
+        .validate()
+    );
+    assert_ok!(Command::Update(5, String::new()).validate());
+    assert_ok!(Command::Delete.validate());
+}
+
+#[test]
+fn enum_invalid_named_field() {
+    assert_eq!(
+        Command::Create {
+            name: String::from(" name")
+        }
+        .validate()
+        .unwrap_err(),
+        invalid("preceding_whitespace", "preceding whitespace", "name")
+    );
+}
+
+#[test]
+fn enum_invalid_tuple_field() {
+    assert_eq!(
+        Command::Update(100, String::new()).validate().unwrap_err(),
+        invalid("not_less_than", "not less than", "0")
+    );
+}
+
+#[test]
+fn enum_invalid_second_tuple_field() {
+    assert_eq!(
+        Command::Update(5, String::from("a b"))
+            .validate()
+            .unwrap_err(),
+        invalid("whitespace", "whitespace", "1")
+    );
+}
+
+// Nested
+
+fn batch(events: Vec<Event>) -> Batch {
+    Batch {
+        events,
+        parent: None,
+        commands: BTreeMap::new(),
+    }
+}
+
+#[test]
+fn nested_valid() {
+    assert_ok!(batch(vec![event("created", 1, "event")]).validate());
+}
+
+#[test]
+fn nested_validators_before_nested() {
+    assert_eq!(
+        batch(Vec::new()).validate().unwrap_err(),
+        invalid("empty", "empty", "events")
+    );
+}
+
+#[test]
+fn nested_vec_prefixes_index() {
+    assert_eq!(
+        batch(vec![event("created", 1, "event"), event("a b", 1, "event")])
+            .validate()
+            .unwrap_err(),
+        invalid("whitespace", "whitespace", "events[1].name")
+    );
+}
+
+#[test]
+fn nested_option_box_and_map() {
+    let mut value = batch(vec![event("created", 1, "event")]);
+
+    value.parent = Some(Box::new(batch(vec![event("created", 0, "event")])));
+
+    assert_eq!(
+        value.validate_nested(Mode::FailFast).unwrap_err(),
+        invalid("not_positive", "not positive", "parent.events[0].version")
+    );
+
+    value.parent = None;
+    value.commands.insert(String::from("x y"), Command::Create {
+        name: String::new(),
+    });
+
+    assert_eq!(
+        value.validate_nested(Mode::FailFast).unwrap_err(),
+        invalid("empty", "empty", r#"commands["x y"].name"#)
+    );
+}
+
+#[test]
+fn nested_all_mode() {
+    let value = batch(vec![event("", 0, "event"), event("a b", 1, "ev\nent")]);
+
+    assert_eq!(
+        value.validate_nested(Mode::All),
+        Err(Error::InvalidAll(vec![
+            Failure::new("empty", "empty").with_path("events[0].name"),
+            Failure::new("not_positive", "not positive").with_path("events[0].version"),
+            Failure::new("whitespace", "whitespace").with_path("events[1].name"),
+            Failure::new("control_characters", "control characters").with_path("events[1].type"),
+        ]))
+    );
+}
+
+// Rules
+
+#[test]
+fn rules_valid() {
+    assert_ok!(Window { start: 1, end: 2 }.validate());
+}
+
+#[test]
+fn rules_after_fields_fail_fast() {
+    assert_eq!(
+        Window { start: 60, end: 10 }.validate().unwrap_err(),
+        invalid("not_less_than", "not less than", "start")
+    );
+}
+
+#[test]
+fn rules_fail_fast() {
+    assert_eq!(
+        Window {
+            start: 10,
+            end: 200
+        }
+        .validate()
+        .unwrap_err(),
+        invalid("invalid", "must be below 100", "end")
+    );
+    assert_eq!(
+        Window { start: 10, end: 5 }.validate().unwrap_err(),
+        Error::InvalidAll(vec![
+            Failure::new("order", "must be before end").with_path("start"),
+            Failure::new("order", "must be after start").with_path("end"),
+        ])
+    );
+}
+
+#[test]
+fn rules_all_mode() {
+    assert_eq!(
+        Window {
+            start: 200,
+            end: 150
+        }
+        .validate_nested(Mode::All),
+        Err(Error::InvalidAll(vec![
+            Failure::new("not_less_than", "not less than").with_path("start"),
+            Failure::new("order", "must be before end").with_path("start"),
+            Failure::new("order", "must be after start").with_path("end"),
+            Failure::new("invalid", "must be below 100").with_path("end"),
+        ]))
+    );
+}
+
+// Conditional
+
+#[test]
+fn conditional_parent_context() {
+    assert_eq!(
+        Name {
+            kind: Kind::Identifier,
+            value: String::from("a b"),
+        }
+        .validate()
+        .unwrap_err(),
+        invalid("whitespace", "whitespace", "value")
+    );
+    assert_ok!(
+        Name {
+            kind: Kind::Label,
+            value: String::from("a b"),
+        }
+        .validate()
+    );
+}
+
+// Patterns
+
+#[test]
+fn pattern_valid() {
+    assert_ok!(EventType(String::from("order.placed")).validate());
+    assert_ok!(EventType(String::from("order")).validate());
+}
+
+#[test]
+fn pattern_invalid() {
+    let pattern = r"[a-z]+(\.[a-z]+)*";
+    let mismatch = Failure::new("pattern", format!("does not match pattern {pattern}"))
+        .with_param("pattern", pattern)
+        .with_path("0");
+
+    assert_eq!(
+        EventType(String::from("Order.Placed"))
+            .validate()
+            .unwrap_err(),
+        Error::Invalid(mismatch.clone())
+    );
+    assert_eq!(
+        EventType(String::new()).validate_nested(Mode::All),
+        Err(Error::InvalidAll(vec![
+            Failure::new("empty", "empty").with_path("0"),
+            mismatch,
+        ]))
+    );
+}
+
+// Reports
+
+#[test]
+fn reported_nested_advisory_failures() {
+    let labelled = Labelled {
+        name: String::new(),
+        labels: vec![Label(String::from("a")), Label(String::from("b c"))],
+    };
+    let mut report = Report::new();
+
+    assert_eq!(
+        labelled.validate_nested_reported(Mode::All, &mut report),
+        Err(Error::InvalidAll(vec![
+            Failure::new("empty", "empty").with_path("name")
+        ]))
+    );
+    assert_eq!(report.into_failures(), vec![
+        Failure::new("whitespace", "whitespace")
+            .with_path("labels[1].0")
+            .with_severity(Severity::Warning)
+    ]);
+    assert_ok!(
+        Labelled {
+            name: String::from("labelled"),
+            ..labelled
+        }
+        .validate()
+    );
+}
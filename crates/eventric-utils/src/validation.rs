@@ -5,13 +5,43 @@
 //!
 //! [validation]: self
 
+mod asynchronous;
+mod catalogue;
+mod characters;
+mod combinators;
+mod conditional;
+mod context;
+mod each;
+mod failure;
+#[cfg(feature = "fluent")]
+mod fluent;
+mod forward;
+mod function;
+mod items;
+mod length;
+#[cfg(feature = "regex")]
+mod matches;
+mod message;
+mod mode;
+mod nested;
 mod no_control_characters;
 mod no_white_space;
 mod not_empty;
+mod numeric;
+mod path;
+mod report;
+mod rule;
+mod sanitize;
+#[cfg(feature = "schema")]
+mod schema;
+#[cfg(feature = "unicode")]
+mod unicode;
+mod validated;
 
 use std::{
     error,
-    fmt::Display,
+    pin::Pin,
+    slice,
 };
 
 use thiserror::Error;
@@ -23,12 +53,99 @@ use thiserror::Error;
 // Traits
 
 /// Defines an implementation to be a validator of the given parameter `T`.
-pub trait Validator<T> {
+pub trait Validator<T>
+where
+    T: ?Sized,
+{
+    /// Validates the given value, returning an optional [`Failure`] if the
+    /// validation criterion is not met. The path of any failure is relative to
+    /// the given value, and will usually be empty.
+    fn validate(&self, value: &T) -> Option<Failure>;
+}
+
+/// Defines an implementation to be a catalogue of validation messages, which
+/// renders a [`Failure`] as a message in a requested locale (given as a
+/// language tag, such as `en` or `de-AT`). Messages are identified by the
+/// stable code of the failure (for example `empty` or `min_length`), and may
+/// refer to the parameters of the failure (such as `min` or `actual`).
+pub trait Catalogue {
+    /// Returns the message for the given failure in the given locale, or
+    /// `None` if the catalogue has no message for the failure in that locale.
+    fn message(&self, failure: &Failure, locale: &str) -> Option<String>;
+}
+
+/// Defines an implementation (usually a validator) to be describable as JSON
+/// Schema keywords, such as `minLength` or `pattern`, so that the constraints
+/// it enforces can be shared with other systems. Where a built-in validator
+/// adds a keyword already present with a different value, the keyword is added
+/// as a further `allOf` subschema, so that both constraints apply.
+#[cfg(feature = "schema")]
+pub trait Describe {
+    /// Adds the keywords describing this implementation to the given schema.
+    fn describe(&self, schema: &mut Schema);
+}
+
+/// Defines an implementation to be a validator of the given parameter `T`
+/// which requires an external context `C`, such as a clock, configuration, or
+/// the current principal. The context is supplied by the caller at validation
+/// time (using [`validate_with`] or [`validate_all_with`]), so that the same
+/// validator can be used with different contexts (for example in tests and in
+/// production). Validators which do not require the context may be used
+/// alongside context validators by wrapping them in the [`Contextless`]
+/// adapter.
+pub trait ContextValidator<T, C>
+where
+    T: ?Sized,
+    C: ?Sized,
+{
+    /// Validates the given value in the given context, returning an optional
+    /// [`Failure`] if the validation criterion is not met. The path of any
+    /// failure is relative to the given value, and will usually be empty.
+    fn validate(&self, value: &T, context: &C) -> Option<Failure>;
+}
+
+/// Defines an implementation to be an asynchronous validator of the given
+/// parameter `T`, for validation criteria which require I/O (such as checking
+/// that an identifier has not already been reserved). Asynchronous validators
+/// are independent of any particular async runtime, and can be run using
+/// [`validate_async`] and [`validate_all_async`]. Synchronous validators may
+/// be run alongside them by wrapping them in the [`Synchronous`] adapter.
+pub trait AsyncValidator<T>: Sync
+where
+    T: ?Sized,
+{
+    /// Validates the given value, returning a future which resolves to an
+    /// optional [`Failure`] if the validation criterion is not met. The path
+    /// of any failure is relative to the given value, and will usually be
+    /// empty.
+    fn validate<'a>(&'a self, value: &'a T) -> ValidationFuture<'a>;
+}
+
+/// The boxed future returned by an [`AsyncValidator`].
+pub type ValidationFuture<'a> = Pin<Box<dyn Future<Output = Option<Failure>> + Send + 'a>>;
+
+/// Defines an implementation to be a validator of the given parameter `T`
+/// which returns a simple borrowed error message, rather than a [`Failure`].
+/// Such validators can be used wherever a [`Validator`] is expected by
+/// wrapping them in the [`Message`] adapter.
+pub trait MessageValidator<T>
+where
+    T: ?Sized,
+{
     /// Validates the given value, returning an optional error message if the
     /// validation criterion is not met.
     fn validate(&self, value: &T) -> Option<&str>;
 }
 
+/// Defines an implementation to be a sanitizer of the given parameter `T`,
+/// which repairs a value (for example by trimming white space) rather than
+/// rejecting it. Sanitizers may be run before validation using
+/// [`sanitize_validate`].
+pub trait Sanitizer<T> {
+    /// Sanitizes the given value, returning the sanitized value.
+    fn sanitize(&self, value: T) -> T;
+}
+
 /// Defines an implementation to be validatable, i.e. that it may or may not be
 /// in a valid state.
 pub trait Validate
@@ -49,35 +166,199 @@ where
     fn validate(self) -> Result<Self, Self::Err>;
 }
 
+/// Defines an implementation to be validatable in a given context `C`, as
+/// [`Validate`], but for values whose validity depends on an external context
+/// supplied by the caller.
+pub trait ValidateWith<C>
+where
+    C: ?Sized,
+    Self::Err: error::Error + From<Error>,
+    Self: Sized,
+{
+    /// The error type to return from validation, which must be convertible from
+    /// the standard validation [`Error`][enum@Error] type.
+    type Err;
+
+    /// Validate self in the given context, and return self if valid, or an
+    /// error if not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation in the given context fails.
+    fn validate_with(self, context: &C) -> Result<Self, Self::Err>;
+}
+
+/// Defines an implementation to be describable as a JSON Schema, giving the
+/// constraints enforced when validating values of the type. The schema is
+/// usually produced using the `Validate` derive macro, and may be emitted as a
+/// complete document using [`schema_document`].
+#[cfg(feature = "schema")]
+pub trait ValidateSchema {
+    /// Returns the JSON Schema of the type.
+    fn schema() -> Schema;
+}
+
+/// Defines an implementation to be validatable by reference, such that it can
+/// be validated as a nested part of a larger value. Any resulting failures have
+/// paths relative to the value itself, which are prefixed by the path of the
+/// value within the larger value when validated using [`validate_nested`].
+pub trait ValidateNested {
+    /// Validates self in the given [`Mode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation fails, which will contain the first
+    /// failure when validating in [`Mode::FailFast`], or all failures when
+    /// validating in [`Mode::All`].
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error>;
+
+    /// Validates self in the given [`Mode`] as
+    /// [`ValidateNested::validate_nested`], additionally collecting any
+    /// advisory failures in the given [`Report`]. The default implementation
+    /// collects none, and should be overridden by values which validate
+    /// advisory validators or nested values (as derived implementations do).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation fails, as described for
+    /// [`ValidateNested::validate_nested`].
+    fn validate_nested_reported(&self, mode: Mode, _report: &mut Report) -> Result<(), Error> {
+        self.validate_nested(mode)
+    }
+}
+
+/// Defines an implementation to be a rule over the whole of a value of the
+/// given parameter `T` (usually a struct), able to express criteria relating
+/// several fields of the value, such as one field being required when another
+/// is set. Rules are implemented for functions and closures of the form
+/// `Fn(&T) -> Result<(), Error>`, and the [`Fields`] adapter may be used for
+/// simple predicates.
+pub trait Rule<T>
+where
+    T: ?Sized,
+{
+    /// Checks the given value, returning an error if the criterion of the rule
+    /// is not met. The paths of any failures are relative to the given value,
+    /// and should name the field (or fields) which the failure concerns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the criterion of the rule is not met.
+    fn check(&self, value: &T) -> Result<(), Error>;
+}
+
 // -------------------------------------------------------------------------------------------------
 
 // Errors
 
 /// The [`Error`] enumeration gives possible error cases when validation fails.
-#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[derive(Clone, Debug, Error, PartialEq)]
 pub enum Error {
-    /// The validation request failed with the supplied error message.
+    /// The validation request failed with the supplied failure.
     #[error("Validation Error: {0}")]
-    Invalid(String),
+    Invalid(Failure),
+    /// The validation request failed with the supplied collection of failures,
+    /// produced when all validations are run rather than stopping at the first
+    /// failure.
+    #[error("Validation Errors: {}", display_all(.0))]
+    InvalidAll(Vec<Failure>),
 }
 
 impl Error {
-    /// Creates an [`Error::Invalid`] variant with the supplied error message.
+    /// Creates an [`Error::Invalid`] variant with the supplied error message,
+    /// with the generic `invalid` code and an empty path.
     pub fn invalid<E>(error: E) -> Self
     where
         E: Into<String>,
     {
-        Self::Invalid(error.into())
+        Self::Invalid(Failure::new(INVALID, error.into()))
+    }
+
+    /// Creates an [`Error::InvalidAll`] variant with the supplied collection of
+    /// error messages, each with the generic `invalid` code and an empty path.
+    pub fn invalid_all<I, E>(errors: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<String>,
+    {
+        Self::InvalidAll(
+            errors
+                .into_iter()
+                .map(|error| Failure::new(INVALID, error.into()))
+                .collect(),
+        )
+    }
+
+    /// Returns the [`Error`] with the given prefix prepended to the path of
+    /// each of its failures.
+    #[must_use]
+    pub fn with_prefix(self, prefix: &Path) -> Self {
+        match self {
+            Self::Invalid(failure) => Self::Invalid(failure.with_prefix(prefix)),
+            Self::InvalidAll(failures) => Self::InvalidAll(
+                failures
+                    .into_iter()
+                    .map(|failure| failure.with_prefix(prefix))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Returns the [`Failure`]s which caused the error.
+    #[must_use]
+    pub fn failures(&self) -> &[Failure] {
+        match self {
+            Self::Invalid(failure) => slice::from_ref(failure),
+            Self::InvalidAll(failures) => failures,
+        }
+    }
+
+    /// Returns the error rendered in the given locale using the given
+    /// [`Catalogue`], with each failure rendered as for
+    /// [`Failure::localize`], prefixed by its path where present.
+    #[must_use]
+    pub fn localize(&self, catalogue: &dyn Catalogue, locale: &str) -> String {
+        self.failures()
+            .iter()
+            .map(|failure| {
+                let message = failure.localize(catalogue, locale);
+
+                if failure.path().is_empty() {
+                    message.into_owned()
+                } else {
+                    format!("{}: {message}", failure.path())
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    /// Consumes the error, returning the [`Failure`]s which caused it.
+    #[must_use]
+    pub fn into_failures(self) -> Vec<Failure> {
+        match self {
+            Self::Invalid(failure) => vec![failure],
+            Self::InvalidAll(failures) => failures,
+        }
     }
 }
 
+fn display_all(failures: &[Failure]) -> String {
+    failures
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 // -------------------------------------------------------------------------------------------------
 
 // Validate
 
 /// Validates a given value, taking a provided name for any resulting error
 /// value, and a collection of validators which can be applied to the given
-/// instance.
+/// instance. Failures of a [`Severity`] lower than [`Severity::Error`] are
+/// ignored (use [`validate_reported`] to collect them).
 ///
 /// # Errors
 ///
@@ -87,29 +368,677 @@ impl Error {
 /// first failure).
 pub fn validate<T, N>(value: &T, name: N, validators: &[&dyn Validator<T>]) -> Result<(), Error>
 where
-    N: Display,
+    T: ?Sized,
+    N: Into<Path>,
+{
+    validate_reported(value, name, validators, &mut Report::new())
+}
+
+/// Validates a given value, taking a provided name for any resulting error
+/// value, and a collection of validators which can be applied to the given
+/// instance. Unlike [`validate`], every validator in the collection is run,
+/// and all failures are returned together. Failures of a [`Severity`] lower
+/// than [`Severity::Error`] are ignored (use [`validate_all_reported`] to
+/// collect them).
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidAll`] error when validation fails, containing
+/// the error produced by each validator in the given collection which failed,
+/// in the order in which the validators were given.
+pub fn validate_all<T, N>(value: &T, name: N, validators: &[&dyn Validator<T>]) -> Result<(), Error>
+where
+    T: ?Sized,
+    N: Into<Path>,
+{
+    validate_all_reported(value, name, validators, &mut Report::new())
+}
+
+/// Validates a given value as [`validate`], additionally collecting any
+/// failures of a [`Severity`] lower than [`Severity::Error`] (warnings and
+/// notices) in the given [`Report`]. Such failures do not cause validation to
+/// fail, or stop subsequent validators from running.
+///
+/// # Errors
+///
+/// Returns an error when validation fails, produced by the first validator in
+/// the given collection to produce a failure of [`Severity::Error`].
+pub fn validate_reported<T, N>(
+    value: &T,
+    name: N,
+    validators: &[&dyn Validator<T>],
+    report: &mut Report,
+) -> Result<(), Error>
+where
+    T: ?Sized,
+    N: Into<Path>,
 {
+    let path = name.into();
+
     for validator in validators {
-        if let Some(error) = validator.validate(value) {
-            return Err(Error::invalid(format!("{name}: {error}")));
+        if let Some(failure) = validator.validate(value) {
+            let failure = failure.with_prefix(&path);
+
+            if failure.is_error() {
+                return Err(Error::Invalid(failure));
+            }
+
+            report.push(failure);
         }
     }
 
     Ok(())
 }
 
+/// Validates a given value as [`validate_all`], additionally collecting any
+/// failures of a [`Severity`] lower than [`Severity::Error`] (warnings and
+/// notices) in the given [`Report`]. Such failures do not cause validation to
+/// fail.
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidAll`] error when validation fails, containing
+/// each failure of [`Severity::Error`], in the order in which the validators
+/// were given.
+pub fn validate_all_reported<T, N>(
+    value: &T,
+    name: N,
+    validators: &[&dyn Validator<T>],
+    report: &mut Report,
+) -> Result<(), Error>
+where
+    T: ?Sized,
+    N: Into<Path>,
+{
+    let path = name.into();
+    let mut failures = Vec::new();
+
+    for validator in validators {
+        if let Some(failure) = validator.validate(value) {
+            let failure = failure.with_prefix(&path);
+
+            if failure.is_error() {
+                failures.push(failure);
+            } else {
+                report.push(failure);
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidAll(failures))
+    }
+}
+
+/// Validates a given value in a given context, taking a provided name for any
+/// resulting error value, and a collection of context validators which can be
+/// applied to the given instance. Failures of a [`Severity`] lower than
+/// [`Severity::Error`] are ignored (use [`validate_with_reported`] to collect
+/// them).
+///
+/// # Errors
+///
+/// Returns an error when validation fails, produced by the first validator in
+/// the given collection to produce an error result (the execution is
+/// short-circuiting, as with [`validate`]).
+pub fn validate_with<T, C, N>(
+    value: &T,
+    context: &C,
+    name: N,
+    validators: &[&dyn ContextValidator<T, C>],
+) -> Result<(), Error>
+where
+    T: ?Sized,
+    C: ?Sized,
+    N: Into<Path>,
+{
+    validate_with_reported(value, context, name, validators, &mut Report::new())
+}
+
+/// Validates a given value in a given context, taking a provided name for any
+/// resulting error value, and a collection of context validators which can be
+/// applied to the given instance. Unlike [`validate_with`], every validator in
+/// the collection is run, and all failures are returned together. Failures of
+/// a [`Severity`] lower than [`Severity::Error`] are ignored (use
+/// [`validate_all_with_reported`] to collect them).
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidAll`] error when validation fails, containing
+/// the error produced by each validator in the given collection which failed,
+/// in the order in which the validators were given.
+pub fn validate_all_with<T, C, N>(
+    value: &T,
+    context: &C,
+    name: N,
+    validators: &[&dyn ContextValidator<T, C>],
+) -> Result<(), Error>
+where
+    T: ?Sized,
+    C: ?Sized,
+    N: Into<Path>,
+{
+    validate_all_with_reported(value, context, name, validators, &mut Report::new())
+}
+
+/// Validates a given value in a given context as [`validate_with`],
+/// additionally collecting any failures of a [`Severity`] lower than
+/// [`Severity::Error`] (warnings and notices) in the given [`Report`]. Such
+/// failures do not cause validation to fail, or stop subsequent validators
+/// from running.
+///
+/// # Errors
+///
+/// Returns an error when validation fails, produced by the first validator in
+/// the given collection to produce a failure of [`Severity::Error`].
+pub fn validate_with_reported<T, C, N>(
+    value: &T,
+    context: &C,
+    name: N,
+    validators: &[&dyn ContextValidator<T, C>],
+    report: &mut Report,
+) -> Result<(), Error>
+where
+    T: ?Sized,
+    C: ?Sized,
+    N: Into<Path>,
+{
+    let path = name.into();
+
+    for validator in validators {
+        if let Some(failure) = validator.validate(value, context) {
+            let failure = failure.with_prefix(&path);
+
+            if failure.is_error() {
+                return Err(Error::Invalid(failure));
+            }
+
+            report.push(failure);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a given value in a given context as [`validate_all_with`],
+/// additionally collecting any failures of a [`Severity`] lower than
+/// [`Severity::Error`] (warnings and notices) in the given [`Report`]. Such
+/// failures do not cause validation to fail.
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidAll`] error when validation fails, containing
+/// each failure of [`Severity::Error`], in the order in which the validators
+/// were given.
+pub fn validate_all_with_reported<T, C, N>(
+    value: &T,
+    context: &C,
+    name: N,
+    validators: &[&dyn ContextValidator<T, C>],
+    report: &mut Report,
+) -> Result<(), Error>
+where
+    T: ?Sized,
+    C: ?Sized,
+    N: Into<Path>,
+{
+    let path = name.into();
+    let mut failures = Vec::new();
+
+    for validator in validators {
+        if let Some(failure) = validator.validate(value, context) {
+            let failure = failure.with_prefix(&path);
+
+            if failure.is_error() {
+                failures.push(failure);
+            } else {
+                report.push(failure);
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidAll(failures))
+    }
+}
+
+/// Validates a given nested value in the given [`Mode`], taking a provided
+/// name which is used to prefix the paths of any resulting failures.
+///
+/// # Errors
+///
+/// Returns an error when validation of the nested value fails, with the paths
+/// of all failures prefixed by the given name.
+pub fn validate_nested<T, N>(value: &T, name: N, mode: Mode) -> Result<(), Error>
+where
+    T: ValidateNested + ?Sized,
+    N: Into<Path>,
+{
+    value
+        .validate_nested(mode)
+        .map_err(|error| error.with_prefix(&name.into()))
+}
+
+/// Validates a given nested value as [`validate_nested`], additionally
+/// collecting any advisory failures in the given [`Report`], with their paths
+/// prefixed by the given name.
+///
+/// # Errors
+///
+/// Returns an error when validation of the nested value fails, with the paths
+/// of all failures prefixed by the given name.
+pub fn validate_nested_reported<T, N>(
+    value: &T,
+    name: N,
+    mode: Mode,
+    report: &mut Report,
+) -> Result<(), Error>
+where
+    T: ValidateNested + ?Sized,
+    N: Into<Path>,
+{
+    let path = name.into();
+    let mut nested = Report::new();
+    let result = value.validate_nested_reported(mode, &mut nested);
+
+    for failure in nested.into_failures() {
+        report.push(failure.with_prefix(&path));
+    }
+
+    result.map_err(|error| error.with_prefix(&path))
+}
+
+/// Sanitizes a given value, applying each of a collection of sanitizers in
+/// order, and returning the sanitized value.
+pub fn sanitize<T>(value: T, sanitizers: &[&dyn Sanitizer<T>]) -> T {
+    sanitizers
+        .iter()
+        .fold(value, |value, sanitizer| sanitizer.sanitize(value))
+}
+
+/// Sanitizes a given value using a collection of sanitizers (as [`sanitize`]),
+/// and then validates the sanitized value using a collection of validators (as
+/// [`validate`]), returning the sanitized value if valid.
+///
+/// # Errors
+///
+/// Returns an error when validation of the sanitized value fails, produced by
+/// the first validator in the given collection to produce an error result.
+pub fn sanitize_validate<T, N>(
+    value: T,
+    name: N,
+    sanitizers: &[&dyn Sanitizer<T>],
+    validators: &[&dyn Validator<T>],
+) -> Result<T, Error>
+where
+    N: Into<Path>,
+{
+    let value = sanitize(value, sanitizers);
+
+    validate(&value, name, validators)?;
+
+    Ok(value)
+}
+
+/// Checks a given value against a collection of [`Rule`]s in the given
+/// [`Mode`]. Where a value has both field validations and rules, rules are
+/// usually checked after the fields have been validated.
+///
+/// # Errors
+///
+/// Returns an error when any rule is not met, which will contain the error of
+/// the first rule not met when checking in [`Mode::FailFast`], or the failures
+/// of all rules not met when checking in [`Mode::All`].
+pub fn validate_rules<T>(value: &T, rules: &[&dyn Rule<T>], mode: Mode) -> Result<(), Error>
+where
+    T: ?Sized,
+{
+    let mut collector = Collector::new(mode);
+
+    for rule in rules {
+        collector.collect(rule.check(value))?;
+    }
+
+    collector.finish()
+}
+
+/// Validates a given value asynchronously, taking a provided name for any
+/// resulting error value, and a collection of asynchronous validators which
+/// can be applied to the given instance. Validators are run in order, one at a
+/// time. Failures of a [`Severity`] lower than [`Severity::Error`] are ignored
+/// (use [`validate_async_reported`] to collect them).
+///
+/// # Errors
+///
+/// Returns an error when validation fails, produced by the first validator in
+/// the given collection to produce an error result (the execution is
+/// short-circuiting, as with [`validate`]).
+pub async fn validate_async<T, N>(
+    value: &T,
+    name: N,
+    validators: &[&dyn AsyncValidator<T>],
+) -> Result<(), Error>
+where
+    T: Sync + ?Sized,
+    N: Into<Path>,
+{
+    validate_async_reported(value, name, validators, &mut Report::new()).await
+}
+
+/// Validates a given value asynchronously, taking a provided name for any
+/// resulting error value, and a collection of asynchronous validators which
+/// can be applied to the given instance. Unlike [`validate_async`], every
+/// validator in the collection is run, and all failures are returned together.
+/// Failures of a [`Severity`] lower than [`Severity::Error`] are ignored (use
+/// [`validate_all_async_reported`] to collect them).
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidAll`] error when validation fails, containing
+/// the error produced by each validator in the given collection which failed,
+/// in the order in which the validators were given.
+pub async fn validate_all_async<T, N>(
+    value: &T,
+    name: N,
+    validators: &[&dyn AsyncValidator<T>],
+) -> Result<(), Error>
+where
+    T: Sync + ?Sized,
+    N: Into<Path>,
+{
+    validate_all_async_reported(value, name, validators, &mut Report::new()).await
+}
+
+/// Validates a given value asynchronously as [`validate_async`], additionally
+/// collecting any failures of a [`Severity`] lower than [`Severity::Error`]
+/// (warnings and notices) in the given [`Report`]. Such failures do not cause
+/// validation to fail, or stop subsequent validators from running.
+///
+/// # Errors
+///
+/// Returns an error when validation fails, produced by the first validator in
+/// the given collection to produce a failure of [`Severity::Error`].
+pub async fn validate_async_reported<T, N>(
+    value: &T,
+    name: N,
+    validators: &[&dyn AsyncValidator<T>],
+    report: &mut Report,
+) -> Result<(), Error>
+where
+    T: Sync + ?Sized,
+    N: Into<Path>,
+{
+    let path = name.into();
+
+    for validator in validators {
+        if let Some(failure) = validator.validate(value).await {
+            let failure = failure.with_prefix(&path);
+
+            if failure.is_error() {
+                return Err(Error::Invalid(failure));
+            }
+
+            report.push(failure);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a given value asynchronously as [`validate_all_async`],
+/// additionally collecting any failures of a [`Severity`] lower than
+/// [`Severity::Error`] (warnings and notices) in the given [`Report`]. Such
+/// failures do not cause validation to fail.
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidAll`] error when validation fails, containing
+/// each failure of [`Severity::Error`], in the order in which the validators
+/// were given.
+pub async fn validate_all_async_reported<T, N>(
+    value: &T,
+    name: N,
+    validators: &[&dyn AsyncValidator<T>],
+    report: &mut Report,
+) -> Result<(), Error>
+where
+    T: Sync + ?Sized,
+    N: Into<Path>,
+{
+    let path = name.into();
+    let mut failures = Vec::new();
+
+    for validator in validators {
+        if let Some(failure) = validator.validate(value).await {
+            let failure = failure.with_prefix(&path);
+
+            if failure.is_error() {
+                failures.push(failure);
+            } else {
+                report.push(failure);
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidAll(failures))
+    }
+}
+
+/// Describes a value as a JSON [`Schema`], given a collection of validators
+/// which are applied to the value, each of which adds the keywords describing
+/// its constraints (see [`Describe`]).
+#[cfg(feature = "schema")]
+#[must_use]
+pub fn describe(validators: &[&dyn Describe]) -> Schema {
+    describe_onto(Schema::new(), validators)
+}
+
+/// Describes a nested value as a JSON [`Schema`], as [`describe`], but
+/// starting from the schema of the nested type (see [`ValidateSchema`]).
+#[cfg(feature = "schema")]
+#[must_use]
+pub fn describe_nested<T>(validators: &[&dyn Describe]) -> Schema
+where
+    T: ValidateSchema + ?Sized,
+{
+    describe_onto(T::schema(), validators)
+}
+
+#[cfg(feature = "schema")]
+fn describe_onto(mut schema: Schema, validators: &[&dyn Describe]) -> Schema {
+    for validator in validators {
+        validator.describe(&mut schema);
+    }
+
+    schema
+}
+
+/// Describes a value as a JSON [`Schema`] object with the given named
+/// properties, each with its own schema (usually produced using [`describe`] or
+/// [`describe_nested`]).
+#[cfg(feature = "schema")]
+#[must_use]
+pub fn describe_object<I, N>(properties: I) -> Schema
+where
+    I: IntoIterator<Item = (N, Schema)>,
+    N: Into<String>,
+{
+    let mut schema = Schema::new();
+
+    schema.insert(String::from("type"), serde_json::Value::from("object"));
+    schema.insert(
+        String::from("properties"),
+        serde_json::Value::Object(
+            properties
+                .into_iter()
+                .map(|(name, property)| (name.into(), serde_json::Value::Object(property)))
+                .collect(),
+        ),
+    );
+
+    schema
+}
+
+/// Returns a complete JSON Schema document (using the 2020-12 dialect) for the
+/// given type, suitable for publishing to other systems.
+#[cfg(feature = "schema")]
+#[must_use]
+pub fn schema_document<T>() -> serde_json::Value
+where
+    T: ValidateSchema + ?Sized,
+{
+    let mut document = Schema::new();
+
+    document.insert(
+        String::from("$schema"),
+        serde_json::Value::from(schema::DIALECT),
+    );
+    document.extend(T::schema());
+
+    serde_json::Value::Object(document)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Codes
+
+const INVALID: &str = "invalid";
+
 // -------------------------------------------------------------------------------------------------
 
 // Re-Exports
 
+#[cfg(feature = "derive")]
+pub use eventric_utils_derive::Validate;
+
+#[cfg(feature = "fluent")]
+pub use self::fluent::{
+    FluentCatalogue,
+    FluentCatalogueError,
+};
+#[cfg(feature = "regex")]
+pub use self::matches::{
+    Matches,
+    MatchesValidation,
+};
+#[cfg(feature = "unicode")]
+pub use self::sanitize::Normalize;
+#[cfg(feature = "schema")]
+pub use self::schema::Schema;
+#[cfg(feature = "unicode")]
+pub use self::unicode::{
+    NoConfusables,
+    NoFormatCharacters,
+    NoMixedScripts,
+    NormalizationForm,
+    Normalized,
+    UnicodeValidation,
+};
 pub use self::{
-    no_control_characters::NoControlCharacters,
+    asynchronous::Synchronous,
+    catalogue::English,
+    characters::{
+        CharacterSet,
+        Characters,
+        CharactersValidation,
+    },
+    combinators::{
+        All,
+        Any,
+        Not,
+        Optional,
+    },
+    conditional::{
+        Condition,
+        Unless,
+        When,
+    },
+    context::Contextless,
+    each::{
+        Each,
+        EachValidation,
+        Keys,
+        KeysValidation,
+    },
+    failure::{
+        Failure,
+        Severity,
+        Value,
+    },
+    function::{
+        FromFn,
+        Predicate,
+    },
+    items::{
+        ItemsRange,
+        ItemsValidation,
+        MaxItems,
+        MinItems,
+        Unique,
+        UniqueBy,
+    },
+    length::{
+        LengthRange,
+        LengthUnit,
+        LengthValidation,
+        MaxLength,
+        MinLength,
+    },
+    message::Message,
+    mode::{
+        Collector,
+        Mode,
+    },
+    no_control_characters::{
+        ControlCharactersValidation,
+        NoControlCharacters,
+    },
     no_white_space::{
         NoPrecedingWhiteSpace,
         NoTrailingWhiteSpace,
         NoWhiteSpace,
+        PrecedingWhiteSpaceValidation,
+        TrailingWhiteSpaceValidation,
+        WhiteSpaceValidation,
+    },
+    not_empty::{
+        IsEmptyValidation,
+        NotEmpty,
+    },
+    numeric::{
+        Finite,
+        Max,
+        Min,
+        MultipleOf,
+        NonNegative,
+        NonZero,
+        NumericValidation,
+        Positive,
+        Range,
+    },
+    path::{
+        Path,
+        Segment,
+    },
+    report::{
+        Advisory,
+        Report,
+    },
+    rule::Fields,
+    sanitize::{
+        CollapseWhiteSpace,
+        Lowercase,
+        StripControlCharacters,
+        Trim,
     },
-    not_empty::NotEmpty,
+    validated::Validated,
 };
 
 // -------------------------------------------------------------------------------------------------
@@ -118,53 +1047,167 @@ pub use self::{
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "unicode")]
+    use std::borrow::Cow;
+
     use assertables::assert_ok;
 
     use crate::validation::{
+        Characters,
+        CharactersValidation,
+        CollapseWhiteSpace,
+        Each,
+        EachValidation,
         Error,
+        Failure,
+        ItemsValidation,
+        LengthRange,
+        LengthUnit,
+        LengthValidation,
+        Lowercase,
+        NoWhiteSpace,
+        NotEmpty,
+        Path,
+        Sanitizer,
+        Segment,
+        StripControlCharacters,
+        Trim,
+        Unique,
         Validator,
+        Value,
+        sanitize,
+        sanitize_validate,
         validate,
+        validate_all,
+    };
+    #[cfg(feature = "regex")]
+    use crate::validation::{
+        Matches,
+        MatchesValidation,
     };
+    #[cfg(feature = "unicode")]
+    use crate::validation::{
+        Normalized,
+        UnicodeValidation,
+    };
+
+    // Test Helpers
+
+    fn failure(path: &str, message: &'static str) -> Failure {
+        Failure::new("invalid", message).with_path(path)
+    }
+
+    fn invalid(path: &str, message: &'static str) -> Error {
+        Error::Invalid(failure(path, message))
+    }
 
     // Test Validators
 
     struct IsPositive;
 
     impl Validator<i32> for IsPositive {
-        fn validate(&self, value: &i32) -> Option<&str> {
-            (*value <= 0).then_some("not positive")
+        fn validate(&self, value: &i32) -> Option<Failure> {
+            (*value <= 0).then(|| Failure::new("not_positive", "not positive"))
         }
     }
 
     struct IsEven;
 
     impl Validator<i32> for IsEven {
-        fn validate(&self, value: &i32) -> Option<&str> {
-            (value % 2 != 0).then_some("not even")
+        fn validate(&self, value: &i32) -> Option<Failure> {
+            (value % 2 != 0).then(|| Failure::new("invalid", "not even"))
         }
     }
 
     struct LessThan100;
 
     impl Validator<i32> for LessThan100 {
-        fn validate(&self, value: &i32) -> Option<&str> {
-            (*value >= 100).then_some("not less than 100")
+        fn validate(&self, value: &i32) -> Option<Failure> {
+            (*value >= 100).then(|| Failure::new("invalid", "not less than 100"))
+        }
+    }
+
+    struct AtMost(i32);
+
+    impl Validator<i32> for AtMost {
+        fn validate(&self, value: &i32) -> Option<Failure> {
+            (*value > self.0).then(|| {
+                Failure::new(
+                    "at_most",
+                    format!("must be at most {}, got {value}", self.0),
+                )
+                .with_param("max", self.0)
+                .with_param("actual", *value)
+            })
         }
     }
 
     struct MinLength(usize);
 
     impl Validator<String> for MinLength {
-        fn validate(&self, value: &String) -> Option<&str> {
-            (value.len() < self.0).then_some("too short")
+        fn validate(&self, value: &String) -> Option<Failure> {
+            (value.len() < self.0).then(|| Failure::new("invalid", "too short"))
         }
     }
 
     struct MaxLength(usize);
 
     impl Validator<String> for MaxLength {
-        fn validate(&self, value: &String) -> Option<&str> {
-            (value.len() > self.0).then_some("too long")
+        fn validate(&self, value: &String) -> Option<Failure> {
+            (value.len() > self.0).then(|| Failure::new("invalid", "too long"))
+        }
+    }
+
+    // Test Types
+
+    #[derive(Eq, Hash, PartialEq)]
+    struct Tag(String);
+
+    impl CharactersValidation for Tag {
+        fn characters_validation(&self, f: &dyn Fn(char) -> bool) -> Option<(usize, char)> {
+            self.0.characters_validation(f)
+        }
+    }
+
+    impl LengthValidation for Tag {
+        fn length_validation(&self, unit: LengthUnit) -> usize {
+            self.0.length_validation(unit)
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    impl MatchesValidation for Tag {
+        fn matches_validation(&self, regex: &regex::Regex) -> bool {
+            self.0.matches_validation(regex)
+        }
+    }
+
+    #[cfg(feature = "unicode")]
+    impl UnicodeValidation for Tag {
+        fn unicode_validation(&self) -> Cow<'_, str> {
+            self.0.unicode_validation()
+        }
+    }
+
+    struct Tags(Vec<Tag>);
+
+    impl EachValidation for Tags {
+        type Item = Tag;
+
+        fn each_validation(&self, validator: &dyn Validator<Tag>) -> Option<Failure> {
+            self.0.each_validation(validator)
+        }
+    }
+
+    impl ItemsValidation for Tags {
+        type Item = Tag;
+
+        fn count_validation(&self) -> usize {
+            self.0.count_validation()
+        }
+
+        fn items_validation(&self) -> impl Iterator<Item = (Segment, &Tag)> {
+            self.0.items_validation()
         }
     }
 
@@ -174,24 +1217,71 @@ mod tests {
     fn error_invalid_with_string() {
         let error = Error::invalid("test error");
 
-        assert_eq!(error, Error::Invalid(String::from("test error")));
+        assert_eq!(error, Error::Invalid(Failure::new("invalid", "test error")));
     }
 
     #[test]
     fn error_invalid_with_str() {
         let error = Error::invalid("static error");
 
-        assert_eq!(error, Error::Invalid(String::from("static error")));
+        assert_eq!(
+            error,
+            Error::Invalid(Failure::new("invalid", "static error"))
+        );
     }
 
     #[test]
     fn error_display_format() {
-        let error = Error::Invalid(String::from("field is invalid"));
+        let error = Error::Invalid(Failure::new("invalid", "field is invalid"));
         let formatted = format!("{error}");
 
         assert_eq!(formatted, "Validation Error: field is invalid");
     }
 
+    #[test]
+    fn error_display_format_with_path() {
+        let error = Error::Invalid(Failure::new("empty", "empty").with_path("events[3].tags[0]"));
+        let formatted = format!("{error}");
+
+        assert_eq!(formatted, "Validation Error: events[3].tags[0]: empty");
+    }
+
+    #[test]
+    fn error_invalid_all_with_strs() {
+        let error = Error::invalid_all(["first error", "second error"]);
+
+        assert_eq!(
+            error,
+            Error::InvalidAll(vec![
+                Failure::new("invalid", "first error"),
+                Failure::new("invalid", "second error")
+            ])
+        );
+    }
+
+    #[test]
+    fn error_failures() {
+        let first = Failure::new("empty", "empty");
+        let second = Failure::new("whitespace", "whitespace");
+
+        assert_eq!(Error::Invalid(first.clone()).failures().len(), 1);
+        assert_eq!(
+            Error::InvalidAll(vec![first.clone(), second.clone()]).failures(),
+            &[first, second]
+        );
+    }
+
+    #[test]
+    fn error_invalid_all_display_format() {
+        let error = Error::invalid_all(["first is invalid", "second is invalid"]);
+        let formatted = format!("{error}");
+
+        assert_eq!(
+            formatted,
+            "Validation Errors: first is invalid; second is invalid"
+        );
+    }
+
     // validate function - single validator
 
     #[test]
@@ -209,7 +1299,9 @@ mod tests {
 
         assert_eq!(
             validate(&value, "number", validators),
-            Err(Error::Invalid(String::from("number: not positive")))
+            Err(Error::Invalid(
+                Failure::new("not_positive", "not positive").with_path("number")
+            ))
         );
     }
 
@@ -238,7 +1330,9 @@ mod tests {
 
         assert_eq!(
             validate(&value, "number", validators),
-            Err(Error::Invalid(String::from("number: not positive")))
+            Err(Error::Invalid(
+                Failure::new("not_positive", "not positive").with_path("number")
+            ))
         );
     }
 
@@ -249,7 +1343,7 @@ mod tests {
 
         assert_eq!(
             validate(&value, "number", validators),
-            Err(Error::Invalid(String::from("number: not even")))
+            Err(invalid("number", "not even"))
         );
     }
 
@@ -260,7 +1354,7 @@ mod tests {
 
         assert_eq!(
             validate(&value, "number", validators),
-            Err(Error::Invalid(String::from("number: not less than 100")))
+            Err(invalid("number", "not less than 100"))
         );
     }
 
@@ -271,7 +1365,9 @@ mod tests {
 
         assert_eq!(
             validate(&value, "number", validators),
-            Err(Error::Invalid(String::from("number: not positive")))
+            Err(Error::Invalid(
+                Failure::new("not_positive", "not positive").with_path("number")
+            ))
         );
     }
 
@@ -292,7 +1388,7 @@ mod tests {
 
         assert_eq!(
             validate(&value, "text", validators),
-            Err(Error::Invalid(String::from("text: too short")))
+            Err(invalid("text", "too short"))
         );
     }
 
@@ -303,7 +1399,7 @@ mod tests {
 
         assert_eq!(
             validate(&value, "text", validators),
-            Err(Error::Invalid(String::from("text: too long")))
+            Err(invalid("text", "too long"))
         );
     }
 
@@ -316,7 +1412,9 @@ mod tests {
 
         assert_eq!(
             validate(&value, String::from("my_field"), validators),
-            Err(Error::Invalid(String::from("my_field: not positive")))
+            Err(Error::Invalid(
+                Failure::new("not_positive", "not positive").with_path("my_field")
+            ))
         );
     }
 
@@ -329,7 +1427,252 @@ mod tests {
 
         assert_eq!(
             validate(&value, format!("{field_name}[{index}]"), validators),
-            Err(Error::Invalid(String::from("field[3]: not positive")))
+            Err(Error::Invalid(
+                Failure::new("not_positive", "not positive").with_path("field[3]")
+            ))
+        );
+    }
+
+    // validate_all function
+
+    #[test]
+    fn validate_all_multiple_validators_all_valid() {
+        let value = 42;
+        let validators: &[&dyn Validator<i32>] = &[&IsPositive, &IsEven, &LessThan100];
+
+        assert_ok!(validate_all(&value, "number", validators));
+    }
+
+    #[test]
+    fn validate_all_no_validators() {
+        let value = 42;
+        let validators: &[&dyn Validator<i32>] = &[];
+
+        assert_ok!(validate_all(&value, "number", validators));
+    }
+
+    #[test]
+    fn validate_all_single_failure() {
+        let value = 43;
+        let validators: &[&dyn Validator<i32>] = &[&IsPositive, &IsEven, &LessThan100];
+
+        assert_eq!(
+            validate_all(&value, "number", validators),
+            Err(Error::InvalidAll(vec![failure("number", "not even")]))
+        );
+    }
+
+    #[test]
+    fn validate_all_multiple_failures() {
+        let value = -101;
+        let validators: &[&dyn Validator<i32>] = &[&IsPositive, &IsEven, &LessThan100];
+
+        assert_eq!(
+            validate_all(&value, "number", validators),
+            Err(Error::InvalidAll(vec![
+                Failure::new("not_positive", "not positive").with_path("number"),
+                failure("number", "not even")
+            ]))
+        );
+    }
+
+    #[test]
+    fn validate_all_failures_in_validator_order() {
+        let value = 101;
+        let validators: &[&dyn Validator<i32>] = &[&LessThan100, &IsEven, &IsPositive];
+
+        assert_eq!(
+            validate_all(&value, "number", validators),
+            Err(Error::InvalidAll(vec![
+                failure("number", "not less than 100"),
+                failure("number", "not even")
+            ]))
+        );
+    }
+
+    // validate function - structured failures
+
+    #[test]
+    fn validate_failure_structure() {
+        let value = -5;
+        let validators: &[&dyn Validator<i32>] = &[&IsPositive];
+        let error = validate(&value, "events[3].count", validators).unwrap_err();
+        let failure = &error.failures()[0];
+
+        assert_eq!(
+            failure.path(),
+            &Path::new().field("events").index(3).field("count")
+        );
+        assert_eq!(failure.code(), "not_positive");
+        assert_eq!(failure.message(), "not positive");
+    }
+
+    #[test]
+    fn validate_default_code() {
+        let value = 43;
+        let validators: &[&dyn Validator<i32>] = &[&IsEven];
+        let error = validate(&value, "number", validators).unwrap_err();
+
+        assert_eq!(error.failures()[0].code(), "invalid");
+    }
+
+    #[test]
+    fn validate_with_path_name() {
+        let value = 43;
+        let validators: &[&dyn Validator<i32>] = &[&IsEven];
+
+        assert_eq!(
+            validate(&value, Path::new().field("events").index(3), validators),
+            Err(invalid("events[3]", "not even"))
+        );
+    }
+
+    #[test]
+    fn validate_display_format() {
+        let value = 43;
+        let validators: &[&dyn Validator<i32>] = &[&IsEven];
+        let error = validate(&value, "number", validators).unwrap_err();
+
+        assert_eq!(error.to_string(), "Validation Error: number: not even");
+    }
+
+    // validate function - parameterised failures
+
+    #[test]
+    fn validate_parameterised_failure() {
+        let value = 71;
+        let validators: &[&dyn Validator<i32>] = &[&AtMost(64)];
+        let error = validate(&value, "count", validators).unwrap_err();
+        let failure = &error.failures()[0];
+
+        assert_eq!(failure.message(), "must be at most 64, got 71");
+        assert_eq!(failure.param("max"), Some(&Value::Integer(64)));
+        assert_eq!(failure.param("actual"), Some(&Value::Integer(71)));
+        assert_eq!(
+            error.to_string(),
+            "Validation Error: count: must be at most 64, got 71"
+        );
+    }
+
+    #[test]
+    fn validate_prefixes_relative_failure_path() {
+        struct FirstNotEmpty;
+
+        impl Validator<Vec<String>> for FirstNotEmpty {
+            fn validate(&self, value: &Vec<String>) -> Option<Failure> {
+                value
+                    .first()
+                    .is_some_and(String::is_empty)
+                    .then(|| Failure::new("empty", "empty").with_path(Path::new().index(0)))
+            }
+        }
+
+        let value = vec![String::new()];
+        let validators: &[&dyn Validator<Vec<String>>] = &[&FirstNotEmpty];
+
+        assert_eq!(
+            validate(&value, "events[3].tags", validators),
+            Err(Error::Invalid(
+                Failure::new("empty", "empty").with_path("events[3].tags[0]")
+            ))
+        );
+    }
+
+    // validate function - unsized values
+
+    #[test]
+    fn validate_unsized_value() {
+        let validators: &[&dyn Validator<str>] = &[&NotEmpty, &NoWhiteSpace];
+
+        assert_ok!(validate("hello", "text", validators));
+        assert_eq!(
+            validate("hello world", "text", validators),
+            Err(Error::Invalid(
+                Failure::new("whitespace", "whitespace").with_path("text")
+            ))
+        );
+    }
+
+    // sanitize functions
+
+    #[test]
+    fn sanitize_applies_in_order() {
+        let sanitizers: &[&dyn Sanitizer<String>] = &[&CollapseWhiteSpace, &Trim, &Lowercase];
+
+        assert_eq!(
+            sanitize(String::from("  Order \t Placed "), sanitizers),
+            "order placed"
+        );
+    }
+
+    #[test]
+    fn sanitize_validate_valid() {
+        let sanitizers: &[&dyn Sanitizer<String>] = &[&StripControlCharacters, &Trim];
+        let validators: &[&dyn Validator<String>] = &[&NotEmpty, &NoWhiteSpace];
+
+        assert_eq!(
+            sanitize_validate(
+                String::from(" order\u{0}placed\n"),
+                "name",
+                sanitizers,
+                validators
+            ),
+            Ok(String::from("orderplaced"))
+        );
+    }
+
+    #[test]
+    fn sanitize_validate_invalid() {
+        let sanitizers: &[&dyn Sanitizer<String>] = &[&Trim];
+        let validators: &[&dyn Validator<String>] = &[&NotEmpty, &NoWhiteSpace];
+
+        assert_eq!(
+            sanitize_validate(String::from("  "), "name", sanitizers, validators),
+            Err(Error::Invalid(
+                Failure::new("empty", "empty").with_path("name")
+            ))
+        );
+    }
+
+    // supporting traits - newtypes
+
+    #[test]
+    fn validate_all_newtype() {
+        let tags = Tags(vec![
+            Tag(String::from("order")),
+            Tag(String::from("order-placed")),
+            Tag(String::from("order")),
+        ]);
+
+        #[cfg(feature = "regex")]
+        assert_eq!(
+            None,
+            Each::new(Matches::new("[a-z-]+").unwrap()).validate(&tags)
+        );
+        #[cfg(feature = "unicode")]
+        assert_eq!(None, Each::new(Normalized::nfc()).validate(&tags));
+
+        assert_eq!(
+            validate_all(&tags, "tags", &[
+                &Each::new(LengthRange::new(1, 8)),
+                &Each::new(Characters::ascii_alphanumeric()),
+                &Unique,
+            ]),
+            Err(Error::InvalidAll(vec![
+                Failure::new("length_range", "length must be between 1 and 8, got 12")
+                    .with_path("tags[1]")
+                    .with_param("min", 1_usize)
+                    .with_param("max", 8_usize)
+                    .with_param("actual", 12_usize),
+                Failure::new("invalid_character", "invalid character '-' at byte 5")
+                    .with_path("tags[1]")
+                    .with_param("character", '-')
+                    .with_param("offset", 5_usize),
+                Failure::new("duplicate", "duplicate of item 0")
+                    .with_path("tags[2]")
+                    .with_param("index", 2_usize)
+                    .with_param("first", 0_usize),
+            ]))
         );
     }
 }
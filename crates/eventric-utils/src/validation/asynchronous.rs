@@ -0,0 +1,262 @@
+use crate::validation::{
+    AsyncValidator,
+    ValidationFuture,
+    Validator,
+};
+
+// =================================================================================================
+// Asynchronous
+// =================================================================================================
+
+/// Adapts a [`Validator`] to be an [`AsyncValidator`], so that synchronous
+/// validators can be run alongside asynchronous validators. The synchronous
+/// validation is run immediately, and the returned future is always ready.
+pub struct Synchronous<V>(pub V);
+
+impl<T, V> AsyncValidator<T> for Synchronous<V>
+where
+    T: ?Sized,
+    V: Validator<T> + Sync,
+{
+    fn validate<'a>(&'a self, value: &'a T) -> ValidationFuture<'a> {
+        let failure = self.0.validate(value);
+
+        Box::pin(async move { failure })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        pin::pin,
+        sync::Arc,
+        task::{
+            Context,
+            Poll,
+            Wake,
+            Waker,
+        },
+        thread::{
+            self,
+            Thread,
+        },
+    };
+
+    use assertables::assert_ok;
+
+    use crate::validation::{
+        Advisory,
+        AsyncValidator,
+        Error,
+        Failure,
+        Mode,
+        NoWhiteSpace,
+        NotEmpty,
+        Report,
+        Severity,
+        ValidationFuture,
+        asynchronous::Synchronous,
+        validate_all_async,
+        validate_all_async_reported,
+        validate_async,
+        validate_async_reported,
+    };
+
+    // Test Executor
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F>(future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    // Test Validators
+
+    struct NotReserved(HashSet<&'static str>);
+
+    impl AsyncValidator<str> for NotReserved {
+        fn validate<'a>(&'a self, value: &'a str) -> ValidationFuture<'a> {
+            Box::pin(async move {
+                self.0
+                    .contains(value)
+                    .then(|| Failure::new("reserved", "reserved"))
+            })
+        }
+    }
+
+    fn not_reserved() -> NotReserved {
+        NotReserved(HashSet::from(["admin", "system"]))
+    }
+
+    // Synchronous
+
+    #[test]
+    fn synchronous_valid() {
+        assert_eq!(block_on(Synchronous(NotEmpty).validate("a")), None);
+    }
+
+    #[test]
+    fn synchronous_invalid() {
+        assert_eq!(
+            block_on(Synchronous(NotEmpty).validate("")),
+            Some(Failure::new("empty", "empty"))
+        );
+    }
+
+    // Validate Async
+
+    #[test]
+    fn validate_async_valid() {
+        assert_ok!(block_on(validate_async("stream", "id", &[
+            &Synchronous(NotEmpty),
+            &not_reserved(),
+        ])));
+    }
+
+    #[test]
+    fn validate_async_invalid() {
+        assert_eq!(
+            block_on(validate_async("admin", "id", &[
+                &Synchronous(NotEmpty),
+                &not_reserved(),
+            ])),
+            Err(Error::Invalid(
+                Failure::new("reserved", "reserved").with_path("id")
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_async_fail_fast() {
+        assert_eq!(
+            block_on(validate_async("", "id", &[
+                &Synchronous(NotEmpty),
+                &not_reserved(),
+            ])),
+            Err(Error::Invalid(
+                Failure::new("empty", "empty").with_path("id")
+            ))
+        );
+    }
+
+    // Validate All Async
+
+    #[test]
+    fn validate_all_async_valid() {
+        assert_ok!(block_on(validate_all_async("stream", "id", &[
+            &Synchronous(NoWhiteSpace),
+            &not_reserved(),
+        ])));
+    }
+
+    #[test]
+    fn validate_all_async_invalid() {
+        let not_reserved = NotReserved(HashSet::from([" admin"]));
+
+        assert_eq!(
+            block_on(validate_all_async(" admin", "id", &[
+                &Synchronous(NoWhiteSpace),
+                &not_reserved,
+            ])),
+            Err(Error::InvalidAll(vec![
+                Failure::new("whitespace", "whitespace").with_path("id"),
+                Failure::new("reserved", "reserved").with_path("id"),
+            ]))
+        );
+    }
+
+    // Validate Async Reported
+
+    #[test]
+    fn validate_async_reported_collects_advisory_failures() {
+        let warning = Failure::new("whitespace", "whitespace")
+            .with_path("id")
+            .with_severity(Severity::Warning);
+        let validators: &[&dyn AsyncValidator<str>] = &[
+            &Synchronous(Advisory::warning(NoWhiteSpace)),
+            &not_reserved(),
+        ];
+        let mut report = Report::new();
+
+        assert_ok!(block_on(validate_async_reported(
+            "a b",
+            "id",
+            validators,
+            &mut report
+        )));
+        assert_eq!(
+            block_on(validate_all_async_reported(
+                "system",
+                "id",
+                validators,
+                &mut report
+            )),
+            Err(Error::InvalidAll(vec![
+                Failure::new("reserved", "reserved").with_path("id")
+            ]))
+        );
+        assert_ok!(block_on(Mode::FailFast.validate_async_reported(
+            "c d",
+            "id",
+            validators,
+            &mut report
+        )));
+        assert_eq!(report.into_failures(), vec![warning.clone(), warning]);
+    }
+
+    // Mode
+
+    #[test]
+    fn mode_validate_async() {
+        let validators: &[&dyn AsyncValidator<str>] = &[&Synchronous(NotEmpty), &not_reserved()];
+
+        assert_ok!(block_on(
+            Mode::FailFast.validate_async("a", "id", validators)
+        ));
+        assert_eq!(
+            block_on(Mode::All.validate_async("system", "id", validators)),
+            Err(Error::InvalidAll(vec![
+                Failure::new("reserved", "reserved").with_path("id")
+            ]))
+        );
+    }
+
+    // Send
+
+    #[test]
+    fn validate_async_is_send() {
+        fn assert_send<F>(_: F)
+        where
+            F: Future + Send,
+        {
+        }
+
+        let validator = not_reserved();
+        let value = String::from("stream");
+
+        assert_send(validate_async(value.as_str(), "id", &[&validator]));
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt::Write as _;
+
+use crate::validation::{
+    Catalogue,
+    Failure,
+    Value,
+};
+
+// =================================================================================================
+// Catalogue
+// =================================================================================================
+
+/// The default English [`Catalogue`], containing a message for each failure
+/// code produced by the built-in validators, for any requested locale.
+/// Failures with free-form messages (such as those with the generic `invalid`
+/// code, or produced by the `any` and `not` combinators) have no catalogue
+/// message, and are rendered using their own message, as are failures whose
+/// message differs from the built-in message for their code (such as those
+/// produced by a [`Matches`][crate::validation::Matches] validator with a
+/// custom message).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+impl Catalogue for English {
+    fn message(&self, failure: &Failure, _: &str) -> Option<String> {
+        template(failure.code())
+            .map(|template| interpolate(template, failure))
+            .filter(|message| message == failure.message())
+    }
+}
+
+fn template(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "confusable_characters" => "confusable characters",
+        "control_characters" => "control characters",
+        "duplicate" => "duplicate of item {first}",
+        "empty" => "empty",
+        "format_characters" => "format characters",
+        "invalid_character" => "invalid character {character} at byte {offset}",
+        "items_range" => "must have between {min} and {max} items, got {actual}",
+        "length_range" => "length must be between {min} and {max}, got {actual}",
+        "max" => "must be at most {max}, got {actual}",
+        "max_items" => "must have at most {max} items, got {actual}",
+        "max_length" => "length must be at most {max}, got {actual}",
+        "min" => "must be at least {min}, got {actual}",
+        "min_items" => "must have at least {min} items, got {actual}",
+        "min_length" => "length must be at least {min}, got {actual}",
+        "mixed_scripts" => "mixed scripts",
+        "negative" => "must not be negative, got {actual}",
+        "not_finite" => "must be finite, got {actual}",
+        "not_multiple_of" => "must be a multiple of {divisor}, got {actual}",
+        "not_normalized" => "not in {form} form",
+        "not_positive" => "must be positive, got {actual}",
+        "pattern" => "does not match pattern {pattern}",
+        "preceding_whitespace" => "preceding whitespace",
+        "range" => "must be between {min} and {max}, got {actual}",
+        "trailing_whitespace" => "trailing whitespace",
+        "whitespace" => "whitespace",
+        "zero" => "zero",
+        _ => return None,
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Functions
+
+fn interpolate(template: &str, failure: &Failure) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+            break;
+        };
+
+        message.push_str(&rest[..start]);
+
+        // Characters are quoted and escaped, as in the built-in messages, so
+        // that control and white space characters remain visible.
+
+        match failure.param(&rest[start + 1..end]) {
+            Some(Value::Char(character)) => {
+                let _ = write!(message, "{character:?}");
+            }
+            Some(value) => message.push_str(&value.to_string()),
+            None => message.push_str(&rest[start..=end]),
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    message.push_str(rest);
+    message
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::{
+        assert_none,
+        assert_some_eq_x,
+    };
+
+    #[cfg(feature = "regex")]
+    use crate::validation::Matches;
+    use crate::validation::{
+        Catalogue,
+        Characters,
+        Error,
+        Failure,
+        MinLength,
+        NotEmpty,
+        Validator,
+        catalogue::English,
+    };
+
+    // Test Catalogues
+
+    struct German;
+
+    impl Catalogue for German {
+        fn message(&self, failure: &Failure, locale: &str) -> Option<String> {
+            (locale.starts_with("de") && failure.code() == "empty").then(|| String::from("leer"))
+        }
+    }
+
+    // English
+
+    #[test]
+    fn english_without_params() {
+        let failure = NotEmpty.validate("").unwrap();
+
+        assert_some_eq_x!(English.message(&failure, "en"), "empty");
+    }
+
+    #[test]
+    fn english_with_params() {
+        let failure = MinLength::new(3).validate("ab").unwrap();
+
+        assert_some_eq_x!(
+            English.message(&failure, "en"),
+            "length must be at least 3, got 2"
+        );
+    }
+
+    #[test]
+    fn english_matches_built_in_messages() {
+        let failures = [
+            Characters::printable().validate("a\u{7}").unwrap(),
+            Characters::ascii_alphanumeric().validate("a'b").unwrap(),
+            NotEmpty.validate("").unwrap(),
+            MinLength::new(3).validate("ab").unwrap(),
+        ];
+
+        for failure in failures {
+            assert_some_eq_x!(English.message(&failure, "en"), failure.message());
+        }
+    }
+
+    #[test]
+    fn english_control_character() {
+        let failure = Characters::printable().validate("a\nb").unwrap();
+
+        assert_some_eq_x!(
+            English.message(&failure, "en"),
+            r"invalid character '\n' at byte 1"
+        );
+    }
+
+    #[test]
+    fn english_custom_message() {
+        let failure = Failure::new("min", "too small")
+            .with_param("min", 3)
+            .with_param("actual", 2);
+
+        assert_none!(English.message(&failure, "en"));
+        assert_eq!(failure.localize(&English, "en"), "too small");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn english_matches_custom_message() {
+        let validator = Matches::new("[a-z]+").unwrap();
+        let failure = validator.validate("ABC").unwrap();
+
+        assert_some_eq_x!(
+            English.message(&failure, "en"),
+            "does not match pattern [a-z]+"
+        );
+
+        let failure = validator
+            .with_message("must be lowercase")
+            .validate("ABC")
+            .unwrap();
+
+        assert_none!(English.message(&failure, "en"));
+        assert_eq!(failure.localize(&English, "en"), "must be lowercase");
+    }
+
+    #[test]
+    fn english_free_form() {
+        assert_none!(English.message(&Failure::new("invalid", "custom"), "en"));
+    }
+
+    // Localize
+
+    #[test]
+    fn failure_localize() {
+        let failure = NotEmpty.validate("").unwrap();
+
+        assert_eq!(failure.localize(&German, "de-AT"), "leer");
+        assert_eq!(failure.localize(&German, "fr"), "empty");
+    }
+
+    #[test]
+    fn error_localize() {
+        let error = Error::InvalidAll(vec![
+            Failure::new("empty", "empty").with_path("name"),
+            Failure::new("invalid", "custom"),
+        ]);
+
+        assert_eq!(error.localize(&German, "de"), "name: leer; custom");
+    }
+}
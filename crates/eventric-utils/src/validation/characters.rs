@@ -0,0 +1,442 @@
+#[cfg(feature = "schema")]
+use std::fmt::Write as _;
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+    ops::RangeInclusive,
+};
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    forward::{
+        forward_strings,
+        forward_wrappers,
+    },
+};
+
+// =================================================================================================
+// Characters
+// =================================================================================================
+
+/// Validates that every character of a value is allowed by (or, when
+/// denying, is not contained in) the given [`CharacterSet`], reporting the
+/// first offending character and its byte offset.
+#[derive(Clone, Debug)]
+pub struct Characters {
+    allow: bool,
+    set: CharacterSet,
+}
+
+impl Characters {
+    /// Creates a new [`Characters`] validator which allows only characters
+    /// contained in the given set.
+    #[must_use]
+    pub fn allow(set: CharacterSet) -> Self {
+        Self { allow: true, set }
+    }
+
+    /// Creates a new [`Characters`] validator which denies any characters
+    /// contained in the given set.
+    #[must_use]
+    pub fn deny(set: CharacterSet) -> Self {
+        Self { allow: false, set }
+    }
+
+    /// Allows only ASCII characters.
+    #[must_use]
+    pub fn ascii() -> Self {
+        Self::allow(CharacterSet::Range('\0'..='\x7f'))
+    }
+
+    /// Allows only ASCII alphanumeric characters (`[A-Za-z0-9]`).
+    #[must_use]
+    pub fn ascii_alphanumeric() -> Self {
+        Self::allow(CharacterSet::Union(alphanumeric()))
+    }
+
+    /// Allows only identifier-safe characters (`[A-Za-z0-9_\-.]`).
+    #[must_use]
+    pub fn identifier() -> Self {
+        let mut sets = alphanumeric();
+
+        sets.push(CharacterSet::Set(Cow::Borrowed("_-.")));
+
+        Self::allow(CharacterSet::Union(sets))
+    }
+
+    /// Allows only hexadecimal digits (`[A-Fa-f0-9]`).
+    #[must_use]
+    pub fn hex() -> Self {
+        Self::allow(CharacterSet::Union(vec![
+            CharacterSet::Range('0'..='9'),
+            CharacterSet::Range('A'..='F'),
+            CharacterSet::Range('a'..='f'),
+        ]))
+    }
+
+    /// Allows only characters of the URL-safe base64 alphabet
+    /// (`[A-Za-z0-9_\-]`), without padding.
+    #[must_use]
+    pub fn base64url() -> Self {
+        let mut sets = alphanumeric();
+
+        sets.push(CharacterSet::Set(Cow::Borrowed("_-")));
+
+        Self::allow(CharacterSet::Union(sets))
+    }
+
+    /// Allows only printable characters (any character which is not a control
+    /// character).
+    #[must_use]
+    pub fn printable() -> Self {
+        Self::allow(CharacterSet::Predicate(|c| !c.is_control()))
+    }
+}
+
+impl<T> Validator<T> for Characters
+where
+    T: CharactersValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        value
+            .characters_validation(&|c| self.set.contains(c) != self.allow)
+            .map(|(offset, character)| {
+                Failure::new(
+                    "invalid_character",
+                    format!("invalid character {character:?} at byte {offset}"),
+                )
+                .with_param("character", character)
+                .with_param("offset", offset)
+            })
+    }
+}
+
+/// Sets given as predicates cannot be described, and add no keywords.
+#[cfg(feature = "schema")]
+impl Describe for Characters {
+    fn describe(&self, schema: &mut Schema) {
+        if let Some(class) = self.set.class() {
+            let negation = if self.allow { "" } else { "^" };
+
+            keyword(schema, "pattern", format!("^[{negation}{class}]*$"));
+        }
+    }
+}
+
+/// A set of characters, for use with the [`Characters`] validator.
+#[derive(Clone, Debug)]
+pub enum CharacterSet {
+    /// Characters for which the predicate returns `true`.
+    Predicate(fn(char) -> bool),
+    /// Characters within the (inclusive) range.
+    Range(RangeInclusive<char>),
+    /// The characters of the string.
+    Set(Cow<'static, str>),
+    /// Characters contained in any of the sets.
+    Union(Vec<CharacterSet>),
+}
+
+impl CharacterSet {
+    /// Returns `true` if the set contains the given character.
+    #[must_use]
+    pub fn contains(&self, character: char) -> bool {
+        match self {
+            Self::Predicate(predicate) => predicate(character),
+            Self::Range(range) => range.contains(&character),
+            Self::Set(set) => set.contains(character),
+            Self::Union(sets) => sets.iter().any(|set| set.contains(character)),
+        }
+    }
+
+    #[cfg(feature = "schema")]
+    fn class(&self) -> Option<String> {
+        match self {
+            Self::Predicate(_) => None,
+            Self::Range(range) => Some(format!(
+                "{}-{}",
+                class_character(*range.start()),
+                class_character(*range.end())
+            )),
+            Self::Set(set) => Some(set.chars().map(class_character).collect()),
+            Self::Union(sets) => sets.iter().map(Self::class).collect(),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Trait
+
+/// Supports validation by [`Characters`]. Non-UTF-8 values are checked (and
+/// offsets given) after lossy conversion.
+pub trait CharactersValidation {
+    /// Returns the byte offset and value of the first character for which the
+    /// given function returns `true`, if any.
+    fn characters_validation(&self, f: &dyn Fn(char) -> bool) -> Option<(usize, char)>;
+}
+
+impl CharactersValidation for OsStr {
+    fn characters_validation(&self, f: &dyn Fn(char) -> bool) -> Option<(usize, char)> {
+        self.to_string_lossy().characters_validation(f)
+    }
+}
+
+impl CharactersValidation for str {
+    fn characters_validation(&self, f: &dyn Fn(char) -> bool) -> Option<(usize, char)> {
+        self.char_indices().find(|(_, c)| f(*c))
+    }
+}
+
+forward_strings!(CharactersValidation {
+    fn characters_validation(&self, f: &dyn Fn(char) -> bool) -> Option<(usize, char)>;
+});
+
+forward_wrappers!(CharactersValidation {
+    fn characters_validation(&self, f: &dyn Fn(char) -> bool) -> Option<(usize, char)>;
+});
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Functions
+
+fn alphanumeric() -> Vec<CharacterSet> {
+    vec![
+        CharacterSet::Range('0'..='9'),
+        CharacterSet::Range('A'..='Z'),
+        CharacterSet::Range('a'..='z'),
+    ]
+}
+
+#[cfg(feature = "schema")]
+fn class_character(character: char) -> String {
+    match character {
+        '\\' | ']' | '[' | '^' | '-' => format!("\\{character}"),
+        ' '..='~' => character.to_string(),
+        character => {
+            character
+                .encode_utf16(&mut [0; 2])
+                .iter()
+                .fold(String::new(), |mut class, unit| {
+                    let _ = write!(class, "\\u{unit:04X}");
+                    class
+                })
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::Cow,
+        ffi::OsString,
+        path::PathBuf,
+        sync::Arc,
+    };
+
+    use assertables::{
+        assert_none,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Failure,
+        Validator as _,
+        characters::{
+            CharacterSet,
+            Characters,
+        },
+    };
+
+    // Test Helpers
+
+    fn invalid(character: char, offset: usize) -> Failure {
+        Failure::new(
+            "invalid_character",
+            format!("invalid character {character:?} at byte {offset}"),
+        )
+        .with_param("character", character)
+        .with_param("offset", offset)
+    }
+
+    // Character Set
+
+    #[test]
+    fn character_set_contains() {
+        let set = CharacterSet::Union(vec![
+            CharacterSet::Range('a'..='f'),
+            CharacterSet::Set(Cow::Borrowed("_-")),
+            CharacterSet::Predicate(char::is_numeric),
+        ]);
+
+        assert!(set.contains('c'));
+        assert!(set.contains('-'));
+        assert!(set.contains('7'));
+        assert!(!set.contains('g'));
+        assert!(!set.contains(' '));
+    }
+
+    // Allow
+
+    #[test]
+    fn allow_range_valid() {
+        let validator = Characters::allow(CharacterSet::Range('a'..='z'));
+
+        assert_none!(validator.validate("abc"));
+        assert_none!(validator.validate(""));
+    }
+
+    #[test]
+    fn allow_range_invalid() {
+        let validator = Characters::allow(CharacterSet::Range('a'..='z'));
+
+        assert_some_eq!(Some(invalid('B', 1)), validator.validate("aBc"));
+    }
+
+    #[test]
+    fn allow_set_invalid_reports_byte_offset() {
+        let validator = Characters::allow(CharacterSet::Set(Cow::Borrowed("äöü")));
+
+        assert_some_eq!(Some(invalid('x', 4)), validator.validate("äöxü"));
+    }
+
+    #[test]
+    fn allow_predicate_valid() {
+        let validator = Characters::allow(CharacterSet::Predicate(char::is_alphabetic));
+
+        assert_none!(validator.validate("naïve"));
+    }
+
+    // Deny
+
+    #[test]
+    fn deny_set_valid() {
+        let validator = Characters::deny(CharacterSet::Set(Cow::Borrowed("/\\")));
+
+        assert_none!(validator.validate("file.txt"));
+    }
+
+    #[test]
+    fn deny_set_invalid() {
+        let validator = Characters::deny(CharacterSet::Set(Cow::Borrowed("/\\")));
+
+        assert_some_eq!(Some(invalid('/', 3)), validator.validate("dir/file.txt"));
+    }
+
+    #[test]
+    fn deny_range_invalid() {
+        let validator = Characters::deny(CharacterSet::Range('0'..='9'));
+
+        assert_some_eq!(Some(invalid('1', 4)), validator.validate("name1"));
+    }
+
+    // Presets
+
+    #[test]
+    fn ascii() {
+        assert_none!(Characters::ascii().validate("hello, world!"));
+        assert_some_eq!(
+            Some(invalid('é', 3)),
+            Characters::ascii().validate("caf\u{e9}")
+        );
+    }
+
+    #[test]
+    fn ascii_alphanumeric() {
+        assert_none!(Characters::ascii_alphanumeric().validate("abc123"));
+        assert_some_eq!(
+            Some(invalid('_', 3)),
+            Characters::ascii_alphanumeric().validate("abc_123")
+        );
+    }
+
+    #[test]
+    fn identifier() {
+        assert_none!(Characters::identifier().validate("my-stream_1.v2"));
+        assert_some_eq!(
+            Some(invalid(' ', 2)),
+            Characters::identifier().validate("my stream")
+        );
+        assert_some_eq!(
+            Some(invalid('/', 1)),
+            Characters::identifier().validate("a/b")
+        );
+    }
+
+    #[test]
+    fn hex() {
+        assert_none!(Characters::hex().validate("deadBEEF09"));
+        assert_some_eq!(Some(invalid('g', 0)), Characters::hex().validate("g0"));
+    }
+
+    #[test]
+    fn base64url() {
+        assert_none!(Characters::base64url().validate("aGVsbG8-d29ybGQ_"));
+        assert_some_eq!(
+            Some(invalid('+', 1)),
+            Characters::base64url().validate("a+b")
+        );
+        assert_some_eq!(
+            Some(invalid('=', 3)),
+            Characters::base64url().validate("aGk==")
+        );
+    }
+
+    #[test]
+    fn printable() {
+        assert_none!(Characters::printable().validate("tab-free text ✓"));
+        assert_some_eq!(
+            Some(invalid('\t', 1)),
+            Characters::printable().validate("a\tb")
+        );
+    }
+
+    // Other Types
+
+    #[test]
+    fn characters_arc_str() {
+        let value: Arc<str> = Arc::from("a b");
+
+        assert_some_eq!(
+            Some(invalid(' ', 1)),
+            Characters::identifier().validate(&value)
+        );
+    }
+
+    #[test]
+    fn characters_osstring() {
+        let value = OsString::from("ab!");
+
+        assert_some_eq!(
+            Some(invalid('!', 2)),
+            Characters::identifier().validate(&value)
+        );
+    }
+
+    #[test]
+    fn characters_pathbuf() {
+        let value = PathBuf::from("a/b");
+
+        assert_some_eq!(
+            Some(invalid('/', 1)),
+            Characters::identifier().validate(&value)
+        );
+    }
+
+    #[test]
+    fn characters_string() {
+        let value = String::from("abc");
+
+        assert_none!(Characters::identifier().validate(&value));
+    }
+}
@@ -0,0 +1,390 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    failure::first_failure,
+};
+
+// =================================================================================================
+// Combinators
+// =================================================================================================
+
+/// Validates that a value passes all of the given validators, returning the
+/// failure of the first validator to fail (the execution is short-circuiting,
+/// as with [`validate`][crate::validation::validate]). Advisory failures do
+/// not stop validation, and the first is returned only where no validator
+/// produces an error.
+pub struct All<'a, T>(&'a [&'a dyn Validator<T>])
+where
+    T: ?Sized;
+
+impl<'a, T> All<'a, T>
+where
+    T: ?Sized,
+{
+    /// Creates a new [`All`] validator from the given validators.
+    #[must_use]
+    pub fn new(validators: &'a [&'a dyn Validator<T>]) -> Self {
+        Self(validators)
+    }
+}
+
+impl<T> Validator<T> for All<'_, T>
+where
+    T: ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        first_failure(
+            self.0
+                .iter()
+                .filter_map(|validator| validator.validate(value)),
+        )
+    }
+}
+
+/// Validates that a value passes at least one of the given validators. If all
+/// validators fail (or no validators are given), the failure has the code
+/// `any`, and a message combining the messages of each failure. A validator
+/// producing only an advisory failure is considered to pass, and where no
+/// validator passes outright, the first advisory failure is returned.
+pub struct Any<'a, T>(&'a [&'a dyn Validator<T>])
+where
+    T: ?Sized;
+
+impl<'a, T> Any<'a, T>
+where
+    T: ?Sized,
+{
+    /// Creates a new [`Any`] validator from the given validators.
+    #[must_use]
+    pub fn new(validators: &'a [&'a dyn Validator<T>]) -> Self {
+        Self(validators)
+    }
+}
+
+impl<T> Validator<T> for Any<'_, T>
+where
+    T: ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let mut advisory = None;
+        let mut messages = Vec::with_capacity(self.0.len());
+
+        for validator in self.0 {
+            match validator.validate(value) {
+                Some(failure) if failure.is_error() => {
+                    messages.push(failure.message().to_owned());
+                }
+                Some(failure) => {
+                    advisory.get_or_insert(failure);
+                }
+                None => return None,
+            }
+        }
+
+        advisory.or_else(|| Some(Failure::new("any", messages.join(" or "))))
+    }
+}
+
+/// Validates that a value fails the given validator, producing a failure with
+/// the code `not` and the given message if the validator passes. An advisory
+/// failure of the given validator is considered a pass.
+pub struct Not<V> {
+    message: Cow<'static, str>,
+    validator: V,
+}
+
+impl<V> Not<V> {
+    /// Creates a new [`Not`] validator from the given validator and failure
+    /// message.
+    pub fn new<M>(validator: V, message: M) -> Self
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        Self {
+            message: message.into(),
+            validator,
+        }
+    }
+}
+
+impl<T, V> Validator<T> for Not<V>
+where
+    T: ?Sized,
+    V: Validator<T>,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        self.validator
+            .validate(value)
+            .is_none_or(|failure| !failure.is_error())
+            .then(|| Failure::new("not", self.message.clone()))
+    }
+}
+
+/// Validates an optional value using the given validator when the value is
+/// `Some`, passing when the value is `None`.
+pub struct Optional<V>(V);
+
+impl<V> Optional<V> {
+    /// Creates a new [`Optional`] validator from the given validator.
+    pub fn new(validator: V) -> Self {
+        Self(validator)
+    }
+}
+
+impl<T, V> Validator<Option<T>> for Optional<V>
+where
+    V: Validator<T>,
+{
+    fn validate(&self, value: &Option<T>) -> Option<Failure> {
+        value.as_ref().and_then(|value| self.0.validate(value))
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<V> Describe for Optional<V>
+where
+    V: Describe,
+{
+    fn describe(&self, schema: &mut Schema) {
+        self.0.describe(schema);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::{
+        assert_none,
+        assert_ok,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Advisory,
+        Error,
+        Failure,
+        NoControlCharacters,
+        NoWhiteSpace,
+        NotEmpty,
+        Severity,
+        Validator,
+        combinators::{
+            All,
+            Any,
+            Not,
+            Optional,
+        },
+        validate,
+    };
+
+    // All
+
+    #[test]
+    fn all_valid() {
+        let validators: &[&dyn Validator<str>] = &[&NotEmpty, &NoWhiteSpace];
+        let validator = All::new(validators);
+
+        assert_none!(validator.validate("name"));
+    }
+
+    #[test]
+    fn all_invalid_returns_first_failure() {
+        let validators: &[&dyn Validator<str>] = &[&NoWhiteSpace, &NoControlCharacters];
+        let validator = All::new(validators);
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate("a\tb")
+        );
+    }
+
+    #[test]
+    fn all_advisory_does_not_hide_error() {
+        let no_control_characters = Advisory::warning(NoControlCharacters);
+        let validators: &[&dyn Validator<str>] = &[&no_control_characters, &NoWhiteSpace];
+
+        assert_eq!(
+            validate("\u{1} b", "x", &[&All::new(validators)]),
+            Err(Error::Invalid(
+                Failure::new("whitespace", "whitespace").with_path("x")
+            ))
+        );
+    }
+
+    #[test]
+    fn all_advisory_only() {
+        let no_control_characters = Advisory::warning(NoControlCharacters);
+        let validators: &[&dyn Validator<str>] = &[&no_control_characters, &NoWhiteSpace];
+
+        assert_some_eq!(
+            Some(
+                Failure::new("control_characters", "control characters")
+                    .with_severity(Severity::Warning)
+            ),
+            All::new(validators).validate("\u{1}b")
+        );
+    }
+
+    #[test]
+    fn all_empty_valid() {
+        let validator = All::<str>::new(&[]);
+
+        assert_none!(validator.validate(""));
+    }
+
+    // Any
+
+    #[test]
+    fn any_valid_first() {
+        let validators: &[&dyn Validator<str>] = &[&NoWhiteSpace, &NotEmpty];
+        let validator = Any::new(validators);
+
+        assert_none!(validator.validate("name"));
+    }
+
+    #[test]
+    fn any_valid_second() {
+        let validators: &[&dyn Validator<str>] = &[&NoWhiteSpace, &NoControlCharacters];
+        let validator = Any::new(validators);
+
+        assert_none!(validator.validate("a b"));
+    }
+
+    #[test]
+    fn any_invalid() {
+        let validators: &[&dyn Validator<str>] = &[&NoWhiteSpace, &NoControlCharacters];
+        let validator = Any::new(validators);
+
+        assert_some_eq!(
+            Some(Failure::new("any", "whitespace or control characters")),
+            validator.validate("a\tb")
+        );
+    }
+
+    #[test]
+    fn any_advisory_passes() {
+        let no_control_characters = Advisory::warning(NoControlCharacters);
+        let validators: &[&dyn Validator<str>] = &[&NoWhiteSpace, &no_control_characters];
+        let validator = Any::new(validators);
+
+        assert_some_eq!(
+            Some(
+                Failure::new("control_characters", "control characters")
+                    .with_severity(Severity::Warning)
+            ),
+            validator.validate("a\tb")
+        );
+        assert_ok!(validate("a\tb", "x", &[&validator]));
+    }
+
+    #[test]
+    fn any_empty_invalid() {
+        let validator = Any::<str>::new(&[]);
+
+        assert_some_eq!(Some(Failure::new("any", "")), validator.validate(""));
+    }
+
+    #[test]
+    fn any_empty_or_identifier() {
+        let is_empty = Not::new(NotEmpty, "not empty");
+        let validators: &[&dyn Validator<str>] = &[&is_empty, &NoWhiteSpace];
+        let validator = Any::new(validators);
+
+        assert_none!(validator.validate(""));
+        assert_none!(validator.validate("name"));
+        assert_some_eq!(
+            Some(Failure::new("any", "not empty or whitespace")),
+            validator.validate("a b")
+        );
+    }
+
+    // Not
+
+    #[test]
+    fn not_valid() {
+        let validator = Not::new(NoWhiteSpace, "must contain whitespace");
+
+        assert_none!(validator.validate("a b"));
+    }
+
+    #[test]
+    fn not_invalid() {
+        let validator = Not::new(NoWhiteSpace, "must contain whitespace");
+
+        assert_some_eq!(
+            Some(Failure::new("not", "must contain whitespace")),
+            validator.validate("ab")
+        );
+    }
+
+    #[test]
+    fn not_advisory_invalid() {
+        let validator = Not::new(Advisory::warning(NotEmpty), "must not be empty");
+
+        assert_some_eq!(
+            Some(Failure::new("not", "must not be empty")),
+            validator.validate("")
+        );
+    }
+
+    #[test]
+    fn not_with_owned_message() {
+        let validator = Not::new(NotEmpty, format!("must be empty, max {}", 0));
+
+        assert_some_eq!(
+            Some(Failure::new("not", "must be empty, max 0")),
+            validator.validate("a")
+        );
+    }
+
+    // Optional
+
+    #[test]
+    fn optional_none_valid() {
+        let validator = Optional::new(NotEmpty);
+        let value: Option<String> = None;
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn optional_some_valid() {
+        let validator = Optional::new(NotEmpty);
+        let value = Some(String::from("name"));
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn optional_some_invalid() {
+        let validator = Optional::new(NotEmpty);
+        let value = Some(String::new());
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn optional_all() {
+        let validators: &[&dyn Validator<String>] = &[&NotEmpty, &NoWhiteSpace];
+        let validator = Optional::new(All::new(validators));
+
+        assert_none!(validator.validate(&None));
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&Some(String::from("a b")))
+        );
+    }
+}
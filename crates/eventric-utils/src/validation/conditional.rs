@@ -0,0 +1,248 @@
+use crate::validation::{
+    Failure,
+    Validator,
+};
+
+// =================================================================================================
+// Conditional
+// =================================================================================================
+
+/// Validates a value using the given validator only when the given
+/// [`Condition`] holds, passing otherwise. The condition may be a predicate
+/// over the value, or a `bool` computed from a parent context (such as
+/// another field of the struct containing the value). To apply several
+/// validators conditionally, combine them using
+/// [`All`][crate::validation::All].
+pub struct When<C, V> {
+    condition: C,
+    validator: V,
+}
+
+impl<C, V> When<C, V> {
+    /// Creates a new [`When`] validator from the given condition and
+    /// validator.
+    pub fn new(condition: C, validator: V) -> Self {
+        Self {
+            condition,
+            validator,
+        }
+    }
+}
+
+impl<T, C, V> Validator<T> for When<C, V>
+where
+    T: ?Sized,
+    C: Condition<T>,
+    V: Validator<T>,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        self.condition
+            .is_met(value)
+            .then(|| self.validator.validate(value))
+            .flatten()
+    }
+}
+
+/// Validates a value using the given validator only when the given
+/// [`Condition`] does not hold, passing otherwise. The inverse of [`When`].
+pub struct Unless<C, V> {
+    condition: C,
+    validator: V,
+}
+
+impl<C, V> Unless<C, V> {
+    /// Creates a new [`Unless`] validator from the given condition and
+    /// validator.
+    pub fn new(condition: C, validator: V) -> Self {
+        Self {
+            condition,
+            validator,
+        }
+    }
+}
+
+impl<T, C, V> Validator<T> for Unless<C, V>
+where
+    T: ?Sized,
+    C: Condition<T>,
+    V: Validator<T>,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (!self.condition.is_met(value))
+            .then(|| self.validator.validate(value))
+            .flatten()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Trait
+
+/// Supports conditional validation by [`When`] and [`Unless`]. Implemented for
+/// `bool` (a condition computed from a parent context, independent of the
+/// value), and for predicate functions of the form `Fn(&T) -> bool`.
+pub trait Condition<T>
+where
+    T: ?Sized,
+{
+    /// Returns `true` if the condition holds for the given value.
+    fn is_met(&self, value: &T) -> bool;
+}
+
+impl<T> Condition<T> for bool
+where
+    T: ?Sized,
+{
+    fn is_met(&self, _: &T) -> bool {
+        *self
+    }
+}
+
+impl<T, F> Condition<T> for F
+where
+    T: ?Sized,
+    F: Fn(&T) -> bool,
+{
+    fn is_met(&self, value: &T) -> bool {
+        self(value)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::{
+        assert_none,
+        assert_ok,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Advisory,
+        All,
+        Error,
+        Failure,
+        MaxLength,
+        NoControlCharacters,
+        NoWhiteSpace,
+        NotEmpty,
+        Validator,
+        conditional::{
+            Unless,
+            When,
+        },
+        validate,
+    };
+
+    // Test Types
+
+    #[derive(PartialEq)]
+    enum Kind {
+        Identifier,
+        Label,
+    }
+
+    struct Name {
+        kind: Kind,
+        value: String,
+    }
+
+    fn whitespace() -> Failure {
+        Failure::new("whitespace", "whitespace")
+    }
+
+    // When
+
+    #[test]
+    fn when_context_true_applies() {
+        assert_some_eq!(
+            Some(whitespace()),
+            When::new(true, NoWhiteSpace).validate("a b")
+        );
+    }
+
+    #[test]
+    fn when_context_false_skips() {
+        assert_none!(When::new(false, NoWhiteSpace).validate("a b"));
+    }
+
+    #[test]
+    fn when_predicate() {
+        let validator = When::new(|value: &str| value.starts_with('#'), NoWhiteSpace);
+
+        assert_none!(validator.validate("a b"));
+        assert_some_eq!(Some(whitespace()), validator.validate("#a b"));
+    }
+
+    #[test]
+    fn when_all() {
+        let validators: &[&dyn Validator<String>] = &[&NotEmpty, &MaxLength::new(3)];
+        let validator = When::new(true, All::new(validators));
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&String::new())
+        );
+    }
+
+    #[test]
+    fn when_advisory_does_not_hide_error() {
+        let no_control_characters = Advisory::warning(NoControlCharacters);
+        let validators: &[&dyn Validator<str>] = &[&no_control_characters, &NoWhiteSpace];
+
+        assert_some_eq!(
+            Some(whitespace()),
+            When::new(true, All::new(validators)).validate("\u{1} b")
+        );
+    }
+
+    // Unless
+
+    #[test]
+    fn unless_context_true_skips() {
+        assert_none!(Unless::new(true, NoWhiteSpace).validate("a b"));
+    }
+
+    #[test]
+    fn unless_context_false_applies() {
+        assert_some_eq!(
+            Some(whitespace()),
+            Unless::new(false, NoWhiteSpace).validate("a b")
+        );
+    }
+
+    #[test]
+    fn unless_predicate() {
+        let validator = Unless::new(|value: &String| value.is_empty(), NoWhiteSpace);
+
+        assert_none!(validator.validate(&String::new()));
+        assert_some_eq!(Some(whitespace()), validator.validate(&String::from(" ")));
+    }
+
+    // Validate
+
+    fn validate_name(name: &Name) -> Result<(), Error> {
+        validate(&name.value, "value", &[
+            &NotEmpty,
+            &When::new(name.kind == Kind::Identifier, NoWhiteSpace),
+        ])
+    }
+
+    #[test]
+    fn validate_parent_context() {
+        assert_eq!(
+            validate_name(&Name {
+                kind: Kind::Identifier,
+                value: String::from("a b"),
+            }),
+            Err(Error::Invalid(whitespace().with_path("value")))
+        );
+        assert_ok!(validate_name(&Name {
+            kind: Kind::Label,
+            value: String::from("a b"),
+        }));
+    }
+}
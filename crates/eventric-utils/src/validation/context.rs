@@ -0,0 +1,267 @@
+use crate::validation::{
+    ContextValidator,
+    Failure,
+    Validator,
+};
+
+// =================================================================================================
+// Context
+// =================================================================================================
+
+/// Adapts a [`Validator`] to be a [`ContextValidator`] for any context, which
+/// the validator ignores, so that validators which do not require a context
+/// can be run alongside those which do.
+pub struct Contextless<V>(pub V);
+
+impl<T, C, V> ContextValidator<T, C> for Contextless<V>
+where
+    T: ?Sized,
+    C: ?Sized,
+    V: Validator<T>,
+{
+    fn validate(&self, value: &T, _: &C) -> Option<Failure> {
+        self.0.validate(value)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use assertables::{
+        assert_none,
+        assert_ok,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Advisory,
+        ContextValidator,
+        Error,
+        Failure,
+        Mode,
+        NoWhiteSpace,
+        NotEmpty,
+        Report,
+        Severity,
+        ValidateWith,
+        context::Contextless,
+        validate_all_with,
+        validate_all_with_reported,
+        validate_with,
+        validate_with_reported,
+    };
+
+    // Test Contexts
+
+    trait Clock {
+        fn now(&self) -> u64;
+    }
+
+    struct FixedClock(u64);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> u64 {
+            self.0
+        }
+    }
+
+    struct Request {
+        tenants: HashSet<&'static str>,
+    }
+
+    // Test Validators
+
+    struct NotInFuture;
+
+    impl ContextValidator<u64, dyn Clock> for NotInFuture {
+        fn validate(&self, value: &u64, clock: &dyn Clock) -> Option<Failure> {
+            (*value > clock.now()).then(|| Failure::new("in_future", "in the future"))
+        }
+    }
+
+    struct AllowedTenant;
+
+    impl ContextValidator<str, Request> for AllowedTenant {
+        fn validate(&self, value: &str, request: &Request) -> Option<Failure> {
+            (!request.tenants.contains(value)).then(|| Failure::new("tenant", "tenant not allowed"))
+        }
+    }
+
+    // Test Types
+
+    #[derive(Debug)]
+    struct Event {
+        tenant: String,
+        timestamp: u64,
+    }
+
+    struct Environment {
+        clock: FixedClock,
+        request: Request,
+    }
+
+    impl ValidateWith<Environment> for Event {
+        type Err = Error;
+
+        fn validate_with(self, environment: &Environment) -> Result<Self, Self::Err> {
+            validate_with(self.tenant.as_str(), &environment.request, "tenant", &[
+                &Contextless(NotEmpty),
+                &AllowedTenant,
+            ])?;
+            validate_with(
+                &self.timestamp,
+                &environment.clock as &dyn Clock,
+                "timestamp",
+                &[&NotInFuture],
+            )?;
+
+            Ok(self)
+        }
+    }
+
+    fn request() -> Request {
+        Request {
+            tenants: HashSet::from(["a", "b"]),
+        }
+    }
+
+    // Contextless
+
+    #[test]
+    fn contextless() {
+        assert_none!(Contextless(NotEmpty).validate("a", &()));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            Contextless(NotEmpty).validate("", &())
+        );
+    }
+
+    // Context Validator
+
+    #[test]
+    fn context_validator_different_contexts() {
+        let value = 100;
+
+        assert_none!(NotInFuture.validate(&value, &FixedClock(100) as &dyn Clock));
+        assert_some_eq!(
+            Some(Failure::new("in_future", "in the future")),
+            NotInFuture.validate(&value, &FixedClock(99) as &dyn Clock)
+        );
+    }
+
+    // Validate With
+
+    #[test]
+    fn validate_with_valid() {
+        assert_ok!(validate_with("a", &request(), "tenant", &[
+            &Contextless(NoWhiteSpace),
+            &AllowedTenant,
+        ]));
+    }
+
+    #[test]
+    fn validate_with_invalid() {
+        assert_eq!(
+            validate_with("c", &request(), "tenant", &[
+                &Contextless(NoWhiteSpace),
+                &AllowedTenant,
+            ]),
+            Err(Error::Invalid(
+                Failure::new("tenant", "tenant not allowed").with_path("tenant")
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_all_with_invalid() {
+        assert_eq!(
+            validate_all_with("c d", &request(), "tenant", &[
+                &Contextless(NoWhiteSpace),
+                &AllowedTenant,
+            ]),
+            Err(Error::InvalidAll(vec![
+                Failure::new("whitespace", "whitespace").with_path("tenant"),
+                Failure::new("tenant", "tenant not allowed").with_path("tenant"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn validate_with_reported_collects_advisory_failures() {
+        let warning = Failure::new("whitespace", "whitespace")
+            .with_path("tenant")
+            .with_severity(Severity::Warning);
+        let validators: &[&dyn ContextValidator<str, Request>] = &[
+            &Contextless(Advisory::warning(NoWhiteSpace)),
+            &AllowedTenant,
+        ];
+        let mut report = Report::new();
+
+        assert_eq!(
+            validate_with_reported("c d", &request(), "tenant", validators, &mut report),
+            Err(Error::Invalid(
+                Failure::new("tenant", "tenant not allowed").with_path("tenant")
+            ))
+        );
+        assert_eq!(
+            validate_all_with_reported("c d", &request(), "tenant", validators, &mut report),
+            Err(Error::InvalidAll(vec![
+                Failure::new("tenant", "tenant not allowed").with_path("tenant")
+            ]))
+        );
+        assert_ok!(Mode::All.validate_with_reported(
+            "a",
+            &request(),
+            "tenant",
+            validators,
+            &mut report
+        ));
+        assert_eq!(report.into_failures(), vec![warning.clone(), warning]);
+    }
+
+    #[test]
+    fn mode_validate_with() {
+        assert_ok!(Mode::All.validate_with("a", &request(), "tenant", &[&AllowedTenant]));
+        assert_eq!(
+            Mode::FailFast.validate_with("", &request(), "tenant", &[
+                &Contextless(NotEmpty),
+                &AllowedTenant,
+            ]),
+            Err(Error::Invalid(
+                Failure::new("empty", "empty").with_path("tenant")
+            ))
+        );
+    }
+
+    // Validate With Trait
+
+    #[test]
+    fn validate_with_trait() {
+        let environment = Environment {
+            clock: FixedClock(1_000),
+            request: request(),
+        };
+
+        assert_ok!(
+            Event {
+                tenant: String::from("a"),
+                timestamp: 1_000,
+            }
+            .validate_with(&environment)
+        );
+        assert_eq!(
+            Event {
+                tenant: String::from("a"),
+                timestamp: 1_001,
+            }
+            .validate_with(&environment)
+            .unwrap_err(),
+            Error::Invalid(Failure::new("in_future", "in the future").with_path("timestamp"))
+        );
+    }
+}
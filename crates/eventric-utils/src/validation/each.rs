@@ -0,0 +1,582 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashMap,
+        HashSet,
+        LinkedList,
+        VecDeque,
+    },
+    fmt::Display,
+};
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    describe,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Path,
+    Validator,
+    failure::first_failure,
+    forward::forward_wrappers,
+};
+
+// =================================================================================================
+// Each
+// =================================================================================================
+
+/// Validates each element of a collection (or each value of a map) using the
+/// given validator, returning the failure of the first element to fail, with
+/// its path prefixed by the index of the element (or the key of the value).
+/// Elements of sets are identified by their own value as a key.
+///
+/// As a [`Validator`] returns a single failure, only the first failing element
+/// is reported, even under [`Mode::All`](crate::validation::Mode::All) or
+/// [`validate_all`](crate::validation::validate_all), which report at most one
+/// failing element for each [`Each`]. To report every failing element,
+/// implement [`ValidateNested`](crate::validation::ValidateNested) for the
+/// element type and validate the collection with
+/// [`validate_nested`](crate::validation::validate_nested) instead.
+///
+/// Advisory failures do not stop validation, and the first is returned only
+/// where no element produces an error. Hash maps and sets are visited in the
+/// order of their keys as strings, so that the element reported does not
+/// depend on the order of the hashes.
+#[derive(Clone, Copy, Debug)]
+pub struct Each<V>(V);
+
+impl<V> Each<V> {
+    /// Creates a new [`Each`] validator from the given validator.
+    pub fn new(validator: V) -> Self {
+        Self(validator)
+    }
+}
+
+impl<T, V> Validator<T> for Each<V>
+where
+    T: EachValidation + ?Sized,
+    V: Validator<T::Item>,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        value.each_validation(&self.0)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<V> Describe for Each<V>
+where
+    V: Describe,
+{
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "items", describe(&[&self.0]));
+        keyword(schema, "additionalProperties", describe(&[&self.0]));
+    }
+}
+
+/// Validates each key of a map using the given validator, returning the
+/// failure of the first key to fail, with its path prefixed by the key. As for
+/// [`Each`], hash maps are visited in the order of their keys as strings.
+#[derive(Clone, Copy, Debug)]
+pub struct Keys<V>(V);
+
+impl<V> Keys<V> {
+    /// Creates a new [`Keys`] validator from the given validator.
+    pub fn new(validator: V) -> Self {
+        Self(validator)
+    }
+}
+
+impl<T, V> Validator<T> for Keys<V>
+where
+    T: KeysValidation + ?Sized,
+    V: Validator<T::Key>,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        value.keys_validation(&self.0)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<V> Describe for Keys<V>
+where
+    V: Describe,
+{
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "propertyNames", describe(&[&self.0]));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Traits
+
+/// Supports validation by [`Each`]. Sequences are validated in order with
+/// index paths, and maps are validated by value with key paths.
+pub trait EachValidation {
+    /// The type of the elements to validate.
+    type Item: ?Sized;
+
+    /// Validates each element using the given validator, returning the first
+    /// failure (if any) with its path prefixed by the position of the element.
+    fn each_validation(&self, validator: &dyn Validator<Self::Item>) -> Option<Failure>;
+}
+
+impl<T, const N: usize> EachValidation for [T; N] {
+    type Item = T;
+
+    fn each_validation(&self, validator: &dyn Validator<T>) -> Option<Failure> {
+        each_indexed(self.iter(), validator)
+    }
+}
+
+impl<T> EachValidation for [T] {
+    type Item = T;
+
+    fn each_validation(&self, validator: &dyn Validator<T>) -> Option<Failure> {
+        each_indexed(self.iter(), validator)
+    }
+}
+
+impl<K, V> EachValidation for BTreeMap<K, V>
+where
+    K: Display,
+{
+    type Item = V;
+
+    fn each_validation(&self, validator: &dyn Validator<V>) -> Option<Failure> {
+        each_keyed(self.iter(), validator)
+    }
+}
+
+impl<T> EachValidation for BTreeSet<T>
+where
+    T: Display,
+{
+    type Item = T;
+
+    fn each_validation(&self, validator: &dyn Validator<T>) -> Option<Failure> {
+        each_keyed(self.iter().map(|value| (value, value)), validator)
+    }
+}
+
+impl<K, V, S> EachValidation for HashMap<K, V, S>
+where
+    K: Display,
+{
+    type Item = V;
+
+    fn each_validation(&self, validator: &dyn Validator<V>) -> Option<Failure> {
+        each_keyed_sorted(self.iter(), validator)
+    }
+}
+
+impl<T, S> EachValidation for HashSet<T, S>
+where
+    T: Display,
+{
+    type Item = T;
+
+    fn each_validation(&self, validator: &dyn Validator<T>) -> Option<Failure> {
+        each_keyed_sorted(self.iter().map(|value| (value, value)), validator)
+    }
+}
+
+impl<T> EachValidation for LinkedList<T> {
+    type Item = T;
+
+    fn each_validation(&self, validator: &dyn Validator<T>) -> Option<Failure> {
+        each_indexed(self.iter(), validator)
+    }
+}
+
+impl<T> EachValidation for Vec<T> {
+    type Item = T;
+
+    fn each_validation(&self, validator: &dyn Validator<T>) -> Option<Failure> {
+        each_indexed(self.iter(), validator)
+    }
+}
+
+impl<T> EachValidation for VecDeque<T> {
+    type Item = T;
+
+    fn each_validation(&self, validator: &dyn Validator<T>) -> Option<Failure> {
+        each_indexed(self.iter(), validator)
+    }
+}
+
+forward_wrappers!(EachValidation {
+    type Item;
+    fn each_validation(&self, validator: &dyn Validator<Self::Item>) -> Option<Failure>;
+});
+
+/// Supports validation by [`Keys`], for maps and other keyed collections.
+pub trait KeysValidation {
+    /// The type of the keys to validate.
+    type Key: ?Sized;
+
+    /// Validates each key using the given validator, returning the first
+    /// failure (if any) with its path prefixed by the key.
+    fn keys_validation(&self, validator: &dyn Validator<Self::Key>) -> Option<Failure>;
+}
+
+impl<K, V> KeysValidation for BTreeMap<K, V>
+where
+    K: Display,
+{
+    type Key = K;
+
+    fn keys_validation(&self, validator: &dyn Validator<K>) -> Option<Failure> {
+        each_keyed(self.keys().map(|key| (key, key)), validator)
+    }
+}
+
+impl<K, V, S> KeysValidation for HashMap<K, V, S>
+where
+    K: Display,
+{
+    type Key = K;
+
+    fn keys_validation(&self, validator: &dyn Validator<K>) -> Option<Failure> {
+        each_keyed_sorted(self.keys().map(|key| (key, key)), validator)
+    }
+}
+
+forward_wrappers!(KeysValidation {
+    type Key;
+    fn keys_validation(&self, validator: &dyn Validator<Self::Key>) -> Option<Failure>;
+});
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Functions
+
+fn each_indexed<'a, I, T>(values: I, validator: &dyn Validator<T>) -> Option<Failure>
+where
+    I: Iterator<Item = &'a T>,
+    T: 'a,
+{
+    first_failure(values.enumerate().filter_map(|(i, value)| {
+        validator
+            .validate(value)
+            .map(|failure| failure.with_prefix(&Path::new().index(i)))
+    }))
+}
+
+fn each_keyed<'a, I, K, T>(entries: I, validator: &dyn Validator<T>) -> Option<Failure>
+where
+    I: Iterator<Item = (&'a K, &'a T)>,
+    K: Display + 'a,
+    T: 'a,
+{
+    first_failure(entries.filter_map(|(key, value)| {
+        validator
+            .validate(value)
+            .map(|failure| failure.with_prefix(&Path::new().key(key.to_string())))
+    }))
+}
+
+fn each_keyed_sorted<'a, I, K, T>(entries: I, validator: &dyn Validator<T>) -> Option<Failure>
+where
+    I: Iterator<Item = (&'a K, &'a T)>,
+    K: Display + 'a,
+    T: 'a,
+{
+    let mut entries = entries
+        .map(|(key, value)| (key.to_string(), value))
+        .collect::<Vec<_>>();
+
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    first_failure(entries.into_iter().filter_map(|(key, value)| {
+        validator
+            .validate(value)
+            .map(|failure| failure.with_prefix(&Path::new().key(key)))
+    }))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{
+            BTreeMap,
+            BTreeSet,
+            HashMap,
+            HashSet,
+            VecDeque,
+        },
+        sync::Arc,
+    };
+
+    use assertables::{
+        assert_none,
+        assert_ok,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Advisory,
+        All,
+        Error,
+        Failure,
+        Max,
+        MaxLength,
+        NoControlCharacters,
+        NoWhiteSpace,
+        NotEmpty,
+        Validator,
+        each::{
+            Each,
+            Keys,
+        },
+        validate,
+    };
+
+    // Test Helpers
+
+    fn whitespace(path: &str) -> Failure {
+        Failure::new("whitespace", "whitespace").with_path(path)
+    }
+
+    // Each
+
+    #[test]
+    fn each_vec_valid() {
+        let value = vec![String::from("a"), String::from("b")];
+
+        assert_none!(Each::new(NoWhiteSpace).validate(&value));
+    }
+
+    #[test]
+    fn each_vec_empty_valid() {
+        let value: Vec<String> = Vec::new();
+
+        assert_none!(Each::new(NotEmpty).validate(&value));
+    }
+
+    #[test]
+    fn each_vec_invalid_first_failing_element() {
+        let value = vec![
+            String::from("a"),
+            String::from("b"),
+            String::from("c d"),
+            String::from("e f"),
+        ];
+
+        assert_some_eq!(
+            Some(whitespace("[2]")),
+            Each::new(NoWhiteSpace).validate(&value)
+        );
+    }
+
+    #[test]
+    fn each_slice_invalid() {
+        let value: &[&str] = &["a", " b"];
+
+        assert_some_eq!(
+            Some(whitespace("[1]")),
+            Each::new(NoWhiteSpace).validate(value)
+        );
+    }
+
+    #[test]
+    fn each_array_invalid() {
+        let value = [1_u8, 20, 3];
+        let validator = Each::new(Max::new(10_u8));
+
+        assert_some_eq!(
+            Some(String::from("[1]")),
+            validator
+                .validate(&value)
+                .map(|failure| failure.path().to_string())
+        );
+    }
+
+    #[test]
+    fn each_vec_deque_invalid() {
+        let value = VecDeque::from([String::new()]);
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty").with_path("[0]")),
+            Each::new(NotEmpty).validate(&value)
+        );
+    }
+
+    #[test]
+    fn each_btreeset_invalid() {
+        let value = BTreeSet::from([String::from("a"), String::from("x y")]);
+
+        assert_some_eq!(
+            Some(whitespace("[\"x y\"]")),
+            Each::new(NoWhiteSpace).validate(&value)
+        );
+    }
+
+    #[test]
+    fn each_btreemap_values_invalid() {
+        let value = BTreeMap::from([("a", String::from("1")), ("b", String::from("2 3"))]);
+
+        assert_some_eq!(
+            Some(whitespace("[\"b\"]")),
+            Each::new(NoWhiteSpace).validate(&value)
+        );
+    }
+
+    #[test]
+    fn each_hashmap_values_invalid() {
+        let value = HashMap::from([(1, String::new())]);
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty").with_path("[\"1\"]")),
+            Each::new(NotEmpty).validate(&value)
+        );
+    }
+
+    #[test]
+    fn each_hashmap_first_failing_key() {
+        let value = HashMap::from([
+            ("d", String::from("4 4")),
+            ("b", String::from("2 2")),
+            ("c", String::from("3 3")),
+            ("a", String::from("1")),
+        ]);
+
+        assert_some_eq!(
+            Some(whitespace("[\"b\"]")),
+            Each::new(NoWhiteSpace).validate(&value)
+        );
+    }
+
+    #[test]
+    fn each_hashset_first_failing_key() {
+        let value = HashSet::from(["z z", "a", "m m", "b b"]);
+
+        assert_some_eq!(
+            Some(whitespace("[\"b b\"]")),
+            Each::new(NoWhiteSpace).validate(&value)
+        );
+    }
+
+    #[test]
+    fn each_all() {
+        let validators: &[&dyn Validator<String>] = &[&NotEmpty, &MaxLength::new(3)];
+        let value = vec![String::from("abc"), String::from("abcd")];
+
+        assert_some_eq!(
+            Some(String::from("[1]: length must be at most 3, got 4")),
+            Each::new(All::new(validators))
+                .validate(&value)
+                .map(|failure| failure.to_string())
+        );
+    }
+
+    #[test]
+    fn each_advisory_does_not_hide_error() {
+        let no_control_characters = Advisory::warning(NoControlCharacters);
+        let validators: &[&dyn Validator<String>] = &[&no_control_characters, &NoWhiteSpace];
+        let value = vec![String::from("\u{1}"), String::from("a b")];
+
+        assert_eq!(
+            validate(&value, "x", &[&Each::new(All::new(validators))]),
+            Err(Error::Invalid(whitespace("x[1]")))
+        );
+    }
+
+    #[test]
+    fn each_nested() {
+        let value = vec![vec![String::from("a")], vec![
+            String::from("b"),
+            String::from("c d"),
+        ]];
+
+        assert_some_eq!(
+            Some(whitespace("[1][1]")),
+            Each::new(Each::new(NoWhiteSpace)).validate(&value)
+        );
+    }
+
+    #[test]
+    fn each_arc_vec() {
+        let value = Arc::new(vec![String::from("a b")]);
+
+        assert_some_eq!(
+            Some(whitespace("[0]")),
+            Each::new(NoWhiteSpace).validate(&value)
+        );
+    }
+
+    // Keys
+
+    #[test]
+    fn keys_valid() {
+        let value = BTreeMap::from([(String::from("a"), 1), (String::from("b"), 2)]);
+
+        assert_none!(Keys::new(NoWhiteSpace).validate(&value));
+    }
+
+    #[test]
+    fn keys_invalid() {
+        let value = BTreeMap::from([(String::from("a"), 1), (String::from("x y"), 2)]);
+
+        assert_some_eq!(
+            Some(whitespace("[\"x y\"]")),
+            Keys::new(NoWhiteSpace).validate(&value)
+        );
+    }
+
+    #[test]
+    fn keys_hashmap_invalid() {
+        let value = HashMap::from([(String::new(), 1)]);
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty").with_path("[\"\"]")),
+            Keys::new(NotEmpty).validate(&value)
+        );
+    }
+
+    #[test]
+    fn keys_hashmap_first_failing_key() {
+        let value = HashMap::from([
+            (String::from("y y"), 1),
+            (String::from("x x"), 2),
+            (String::from("z"), 3),
+        ]);
+
+        assert_some_eq!(
+            Some(whitespace("[\"x x\"]")),
+            Keys::new(NoWhiteSpace).validate(&value)
+        );
+    }
+
+    // Validate
+
+    #[test]
+    fn validate_each() {
+        let tags = vec![String::from("a"), String::from("b"), String::from("c d")];
+
+        assert_eq!(
+            validate(&tags, "tags", &[&NotEmpty, &Each::new(NoWhiteSpace)])
+                .unwrap_err()
+                .to_string(),
+            "Validation Error: tags[2]: whitespace"
+        );
+    }
+
+    #[test]
+    fn validate_keys() {
+        let metadata = BTreeMap::from([(String::from("x y"), String::from("z"))]);
+
+        assert_eq!(
+            validate(&metadata, "metadata", &[&Keys::new(NoWhiteSpace)]),
+            Err(Error::Invalid(whitespace("metadata[\"x y\"]")))
+        );
+        assert_ok!(validate(&metadata, "metadata", &[&Each::new(NoWhiteSpace)]));
+    }
+}
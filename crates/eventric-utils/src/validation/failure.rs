@@ -0,0 +1,476 @@
+use std::{
+    borrow::Cow,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    num::{
+        NonZeroI8,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize,
+        NonZeroU8,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize,
+    },
+};
+
+use crate::validation::{
+    Catalogue,
+    Path,
+};
+
+// =================================================================================================
+// Failure
+// =================================================================================================
+
+/// A single structured validation failure, giving the [`Path`] to the value
+/// which failed, a stable machine-readable code identifying the criterion
+/// which was not met (for example `empty` or `whitespace`), a human-readable
+/// message, and any typed parameters relevant to the failure.
+///
+/// A failure has a [`Severity`], which is [`Severity::Error`] unless otherwise
+/// specified. Failures of a lower severity are advisory, and do not cause
+/// validation to fail.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Failure {
+    path: Path,
+    code: Cow<'static, str>,
+    message: Cow<'static, str>,
+    params: Vec<(Cow<'static, str>, Value)>,
+    severity: Severity,
+}
+
+impl Failure {
+    /// Creates a new [`Failure`] with the given code and message, an empty
+    /// path, and no parameters.
+    pub fn new<C, M>(code: C, message: M) -> Self
+    where
+        C: Into<Cow<'static, str>>,
+        M: Into<Cow<'static, str>>,
+    {
+        Self {
+            path: Path::new(),
+            code: code.into(),
+            message: message.into(),
+            params: Vec::new(),
+            severity: Severity::Error,
+        }
+    }
+
+    /// Returns the [`Failure`] with the given path.
+    #[must_use]
+    pub fn with_path<P>(mut self, path: P) -> Self
+    where
+        P: Into<Path>,
+    {
+        self.path = path.into();
+        self
+    }
+
+    /// Returns the [`Failure`] with the given prefix prepended to its path.
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: &Path) -> Self {
+        self.path = prefix.join(&self.path);
+        self
+    }
+
+    /// Returns the [`Failure`] with the given named parameter added.
+    #[must_use]
+    pub fn with_param<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        V: Into<Value>,
+    {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+
+    /// Returns the [`Failure`] with the given [`Severity`].
+    #[must_use]
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Returns the [`Path`] to the value which failed validation.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the machine-readable code of the failure.
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the human-readable message of the failure.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the named parameters of the failure, in the order in which they
+    /// were added.
+    #[must_use]
+    pub fn params(&self) -> &[(Cow<'static, str>, Value)] {
+        &self.params
+    }
+
+    /// Returns the value of the named parameter, if present.
+    #[must_use]
+    pub fn param(&self, name: &str) -> Option<&Value> {
+        self.params
+            .iter()
+            .find_map(|(param, value)| (param == name).then_some(value))
+    }
+
+    /// Returns the message of the failure rendered in the given locale using
+    /// the given [`Catalogue`], falling back to the message of the failure
+    /// where the catalogue has no message for it.
+    #[must_use]
+    pub fn localize(&self, catalogue: &dyn Catalogue, locale: &str) -> Cow<'_, str> {
+        catalogue
+            .message(self, locale)
+            .map_or_else(|| Cow::Borrowed(self.message()), Cow::Owned)
+    }
+
+    /// Returns the [`Severity`] of the failure.
+    #[must_use]
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns whether the failure is of [`Severity::Error`], and so causes
+    /// validation to fail.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Severity
+
+/// The severity of a [`Failure`]. Only failures of [`Severity::Error`] cause
+/// validation to fail, failures of lower severities are collected in a
+/// [`Report`](crate::validation::Report) where requested, and are otherwise
+/// ignored.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// An informational failure, such as use of a value which will be
+    /// deprecated in future.
+    Notice,
+    /// A suspicious or deprecated value, which is accepted but should be
+    /// surfaced to the caller.
+    Warning,
+    /// A value which is not valid.
+    #[default]
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Notice => write!(f, "notice"),
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Value
+
+/// A typed parameter value of a [`Failure`], such as a limit which was
+/// exceeded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A boolean value.
+    Bool(bool),
+    /// A character value.
+    Char(char),
+    /// A floating point value.
+    Float(f64),
+    /// A signed integer value.
+    Integer(i128),
+    /// A string value.
+    String(Cow<'static, str>),
+    /// An unsigned integer value.
+    Unsigned(u128),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Char(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value}"),
+            Self::Unsigned(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! value_from {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Value {
+                fn from(value: $source) -> Self {
+                    Self::$variant(<$target>::from(value))
+                }
+            }
+        )*
+    };
+}
+
+value_from!(Bool, bool, bool);
+value_from!(Char, char, char);
+value_from!(Float, f64, f32, f64);
+value_from!(Integer, i128, i8, i16, i32, i64, i128);
+value_from!(Unsigned, u128, u8, u16, u32, u64, u128);
+
+impl From<isize> for Value {
+    fn from(value: isize) -> Self {
+        Self::Integer(value as i128)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Self::Unsigned(value as u128)
+    }
+}
+
+macro_rules! value_from_non_zero {
+    ($($source:ty),*) => {
+        $(
+            impl From<$source> for Value {
+                fn from(value: $source) -> Self {
+                    Self::from(value.get())
+                }
+            }
+        )*
+    };
+}
+
+value_from_non_zero!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+
+impl From<&'static str> for Value {
+    fn from(value: &'static str) -> Self {
+        Self::String(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(Cow::Owned(value))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Functions
+
+/// Returns the first of the given failures of [`Severity::Error`], or where
+/// there is none, the first advisory failure, so that validators combining
+/// others never let an advisory failure hide an error.
+pub(crate) fn first_failure<I>(failures: I) -> Option<Failure>
+where
+    I: IntoIterator<Item = Failure>,
+{
+    let mut advisory = None;
+
+    for failure in failures {
+        if failure.is_error() {
+            return Some(failure);
+        }
+
+        advisory.get_or_insert(failure);
+    }
+
+    advisory
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::num::{
+        NonZeroI32,
+        NonZeroU8,
+        NonZeroUsize,
+    };
+
+    use assertables::{
+        assert_none,
+        assert_some_eq_x,
+    };
+
+    use crate::validation::{
+        Path,
+        failure::{
+            Failure,
+            Severity,
+            Value,
+        },
+    };
+
+    // Failure
+
+    #[test]
+    fn failure_new() {
+        let failure = Failure::new("empty", "empty");
+
+        assert!(failure.path().is_empty());
+        assert_eq!(failure.code(), "empty");
+        assert_eq!(failure.message(), "empty");
+        assert_eq!(failure.params(), []);
+        assert_eq!(failure.severity(), Severity::Error);
+        assert!(failure.is_error());
+    }
+
+    #[test]
+    fn failure_with_severity() {
+        let failure = Failure::new("uppercase", "uppercase").with_severity(Severity::Warning);
+
+        assert_eq!(failure.severity(), Severity::Warning);
+        assert!(!failure.is_error());
+    }
+
+    #[test]
+    fn failure_with_path() {
+        let failure = Failure::new("empty", "empty").with_path("events[3].tags[0]");
+
+        assert_eq!(
+            failure.path(),
+            &Path::new().field("events").index(3).field("tags").index(0)
+        );
+    }
+
+    #[test]
+    fn failure_with_prefix() {
+        let failure = Failure::new("empty", "empty")
+            .with_path("tags[0]")
+            .with_prefix(&Path::new().field("events").index(3));
+
+        assert_eq!(failure.path(), &Path::from("events[3].tags[0]"));
+    }
+
+    #[test]
+    fn failure_with_params() {
+        let failure = Failure::new("max_length", "too long")
+            .with_param("max", 64_usize)
+            .with_param("actual", 71_usize);
+
+        assert_some_eq_x!(failure.param("max"), &Value::Unsigned(64));
+        assert_some_eq_x!(failure.param("actual"), &Value::Unsigned(71));
+        assert_none!(failure.param("min"));
+    }
+
+    #[test]
+    fn failure_display_without_path() {
+        let failure = Failure::new("whitespace", "whitespace");
+
+        assert_eq!(failure.to_string(), "whitespace");
+    }
+
+    #[test]
+    fn failure_display_with_path() {
+        let failure = Failure::new("whitespace", "whitespace").with_path("events[3].tags[0]");
+
+        assert_eq!(failure.to_string(), "events[3].tags[0]: whitespace");
+    }
+
+    // Severity
+
+    #[test]
+    fn severity_order() {
+        assert!(Severity::Notice < Severity::Warning);
+        assert!(Severity::Warning < Severity::Error);
+        assert_eq!(Severity::default(), Severity::Error);
+    }
+
+    #[test]
+    fn severity_display() {
+        assert_eq!(Severity::Notice.to_string(), "notice");
+        assert_eq!(Severity::Warning.to_string(), "warning");
+        assert_eq!(Severity::Error.to_string(), "error");
+    }
+
+    // Value
+
+    #[test]
+    fn value_from_primitives() {
+        assert_eq!(Value::from(true), Value::Bool(true));
+        assert_eq!(Value::from('x'), Value::Char('x'));
+        assert_eq!(Value::from(1.5_f32), Value::Float(1.5));
+        assert_eq!(Value::from(-3_i32), Value::Integer(-3));
+        assert_eq!(Value::from(3_u8), Value::Unsigned(3));
+        assert_eq!(Value::from(-3_isize), Value::Integer(-3));
+        assert_eq!(Value::from(3_usize), Value::Unsigned(3));
+        assert_eq!(Value::from("text"), Value::String("text".into()));
+        assert_eq!(
+            Value::from(String::from("text")),
+            Value::String("text".into())
+        );
+    }
+
+    #[test]
+    fn value_from_non_zero() {
+        assert_eq!(
+            Value::from(NonZeroI32::new(-3).unwrap()),
+            Value::Integer(-3)
+        );
+        assert_eq!(Value::from(NonZeroU8::new(3).unwrap()), Value::Unsigned(3));
+        assert_eq!(
+            Value::from(NonZeroUsize::new(3).unwrap()),
+            Value::Unsigned(3)
+        );
+    }
+
+    #[test]
+    fn value_display() {
+        assert_eq!(Value::Bool(true).to_string(), "true");
+        assert_eq!(Value::Char('x').to_string(), "x");
+        assert_eq!(Value::Float(1.5).to_string(), "1.5");
+        assert_eq!(Value::Integer(-3).to_string(), "-3");
+        assert_eq!(Value::String("text".into()).to_string(), "text");
+        assert_eq!(Value::Unsigned(3).to_string(), "3");
+    }
+}
@@ -0,0 +1,257 @@
+use fluent_bundle::{
+    FluentArgs,
+    FluentResource,
+    FluentValue,
+    concurrent::FluentBundle,
+};
+use thiserror::Error;
+use unic_langid::LanguageIdentifier;
+
+use crate::validation::{
+    Catalogue,
+    Failure,
+    Value,
+};
+
+// =================================================================================================
+// Fluent
+// =================================================================================================
+
+/// A [`Catalogue`] of messages loaded from [Fluent](https://projectfluent.org)
+/// resources, one or more per locale. Messages are identified by failure code,
+/// and the parameters of a failure are available to messages as variables, so
+/// that (for example) a `min_length` failure may be rendered using a message
+/// such as `min_length = mindestens { $min } Zeichen`.
+///
+/// Where no resources have been added for the requested locale, resources for
+/// a locale with the same language are used (so that a request for `de-AT`
+/// may be served by resources for `de`).
+#[derive(Default)]
+pub struct FluentCatalogue {
+    bundles: Vec<(LanguageIdentifier, FluentBundle<FluentResource>)>,
+}
+
+impl FluentCatalogue {
+    /// Creates a new empty [`FluentCatalogue`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given Fluent resource source to the catalogue for the given
+    /// locale. Messages in resources added later take precedence over
+    /// messages with the same code in resources added earlier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the locale is not a valid language tag, or if the
+    /// resource cannot be parsed.
+    pub fn add_resource<S>(&mut self, locale: &str, source: S) -> Result<(), FluentCatalogueError>
+    where
+        S: Into<String>,
+    {
+        let locale = locale
+            .parse::<LanguageIdentifier>()
+            .map_err(|_| FluentCatalogueError::Locale(locale.to_owned()))?;
+
+        let resource = FluentResource::try_new(source.into()).map_err(|(_, errors)| {
+            FluentCatalogueError::Resource(
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+        })?;
+
+        let index = self
+            .bundles
+            .iter()
+            .position(|(id, _)| *id == locale)
+            .unwrap_or_else(|| {
+                let mut bundle = FluentBundle::new_concurrent(vec![locale.clone()]);
+
+                bundle.set_use_isolating(false);
+                self.bundles.push((locale, bundle));
+                self.bundles.len() - 1
+            });
+
+        self.bundles[index].1.add_resource_overriding(resource);
+
+        Ok(())
+    }
+
+    fn bundle(&self, locale: &str) -> Option<&FluentBundle<FluentResource>> {
+        let locale = locale.parse::<LanguageIdentifier>().ok()?;
+
+        self.bundles
+            .iter()
+            .find(|(id, _)| *id == locale)
+            .or_else(|| {
+                self.bundles
+                    .iter()
+                    .find(|(id, _)| id.language == locale.language)
+            })
+            .map(|(_, bundle)| bundle)
+    }
+}
+
+impl Catalogue for FluentCatalogue {
+    fn message(&self, failure: &Failure, locale: &str) -> Option<String> {
+        let bundle = self.bundle(locale)?;
+        let pattern = bundle.get_message(failure.code())?.value()?;
+
+        let mut args = FluentArgs::new();
+
+        for (name, value) in failure.params() {
+            args.set(name.clone(), fluent_value(value));
+        }
+
+        let mut errors = Vec::new();
+        let message = bundle.format_pattern(pattern, Some(&args), &mut errors);
+
+        errors.is_empty().then(|| message.into_owned())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Errors
+
+/// The error type for loading Fluent resources into a [`FluentCatalogue`].
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum FluentCatalogueError {
+    /// The given locale was not a valid language tag.
+    #[error("Invalid Locale: {0}")]
+    Locale(String),
+    /// The given resource could not be parsed.
+    #[error("Invalid Resource: {0}")]
+    Resource(String),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Functions
+
+fn fluent_value(value: &Value) -> FluentValue<'static> {
+    match value {
+        Value::Bool(value) => FluentValue::from(value.to_string()),
+        Value::Char(value) => FluentValue::from(value.to_string()),
+        Value::Float(value) => FluentValue::from(*value),
+        Value::Integer(value) => FluentValue::from(*value),
+        Value::String(value) => FluentValue::from(value.to_string()),
+        Value::Unsigned(value) => FluentValue::from(*value),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::{
+        assert_none,
+        assert_ok,
+        assert_some_eq_x,
+    };
+
+    use crate::validation::{
+        Catalogue,
+        Error,
+        Failure,
+        MinLength,
+        NotEmpty,
+        Validator,
+        fluent::{
+            FluentCatalogue,
+            FluentCatalogueError,
+        },
+    };
+
+    // Test Catalogues
+
+    fn catalogue() -> FluentCatalogue {
+        let mut catalogue = FluentCatalogue::new();
+
+        assert_ok!(catalogue.add_resource(
+            "de",
+            "empty = leer\nmin_length = mindestens { $min } Zeichen, { $actual } erhalten\n"
+        ));
+        assert_ok!(catalogue.add_resource("fr-FR", "empty = vide\n"));
+
+        catalogue
+    }
+
+    // Fluent Catalogue
+
+    #[test]
+    fn fluent_without_params() {
+        let failure = NotEmpty.validate("").unwrap();
+
+        assert_some_eq_x!(catalogue().message(&failure, "de"), "leer");
+        assert_some_eq_x!(catalogue().message(&failure, "fr-FR"), "vide");
+    }
+
+    #[test]
+    fn fluent_with_params() {
+        let failure = MinLength::new(3).validate("ab").unwrap();
+
+        assert_some_eq_x!(
+            catalogue().message(&failure, "de"),
+            "mindestens 3 Zeichen, 2 erhalten"
+        );
+    }
+
+    #[test]
+    fn fluent_language_fallback() {
+        let failure = NotEmpty.validate("").unwrap();
+
+        assert_some_eq_x!(catalogue().message(&failure, "de-AT"), "leer");
+        assert_some_eq_x!(catalogue().message(&failure, "fr"), "vide");
+    }
+
+    #[test]
+    fn fluent_missing() {
+        let failure = NotEmpty.validate("").unwrap();
+
+        assert_none!(catalogue().message(&failure, "es"));
+        assert_none!(catalogue().message(&Failure::new("zero", "zero"), "de"));
+        assert_none!(catalogue().message(&failure, "not a locale"));
+    }
+
+    #[test]
+    fn fluent_overriding() {
+        let mut catalogue = catalogue();
+
+        assert_ok!(catalogue.add_resource("de", "empty = nicht leer\n"));
+        assert_some_eq_x!(
+            catalogue.message(&NotEmpty.validate("").unwrap(), "de"),
+            "nicht leer"
+        );
+    }
+
+    #[test]
+    fn fluent_localize() {
+        let error = Error::Invalid(Failure::new("empty", "empty").with_path("name"));
+
+        assert_eq!(error.localize(&catalogue(), "de"), "name: leer");
+        assert_eq!(error.localize(&catalogue(), "es"), "name: empty");
+    }
+
+    #[test]
+    fn fluent_invalid_locale() {
+        assert_eq!(
+            FluentCatalogue::new().add_resource("not a locale", "empty = leer\n"),
+            Err(FluentCatalogueError::Locale(String::from("not a locale")))
+        );
+    }
+
+    #[test]
+    fn fluent_invalid_resource() {
+        assert!(matches!(
+            FluentCatalogue::new().add_resource("de", "empty leer\n"),
+            Err(FluentCatalogueError::Resource(_))
+        ));
+    }
+}
@@ -0,0 +1,69 @@
+// =================================================================================================
+// Forward
+// =================================================================================================
+
+// Wrappers
+
+/// Implements a supporting validation trait for references, `Arc`, `Box`,
+/// `Cow`, and `Rc`, forwarding associated types and methods to the
+/// implementation for the wrapped value.
+macro_rules! forward_wrappers {
+    ($trait:ident { $($body:tt)* }) => {
+        $crate::validation::forward::forward_wrappers!(@impl &T, $trait, [], { $($body)* });
+        $crate::validation::forward::forward_wrappers!(@impl ::std::sync::Arc<T>, $trait, [], { $($body)* });
+        $crate::validation::forward::forward_wrappers!(@impl ::std::boxed::Box<T>, $trait, [], { $($body)* });
+        $crate::validation::forward::forward_wrappers!(@impl ::std::rc::Rc<T>, $trait, [], { $($body)* });
+        $crate::validation::forward::forward_wrappers!(
+            @impl ::std::borrow::Cow<'_, T>, $trait, [+ ::std::borrow::ToOwned], { $($body)* }
+        );
+    };
+    (
+        @impl $wrapper:ty, $trait:ident, [$($bound:tt)*], {
+            $(type $assoc:ident;)*
+            $(fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)*) -> $ret:ty;)+
+        }
+    ) => {
+        impl<T> $trait for $wrapper
+        where
+            T: $trait $($bound)* + ?Sized,
+        {
+            $(type $assoc = T::$assoc;)*
+
+            $(
+                fn $method(&self $(, $arg: $arg_ty)*) -> $ret {
+                    (**self).$method($($arg),*)
+                }
+            )+
+        }
+    };
+}
+
+// Strings
+
+/// Implements a supporting validation trait for `OsString`, `Path`, `PathBuf`,
+/// and `String`, forwarding methods to the hand-written implementations for
+/// `OsStr` and `str`.
+macro_rules! forward_strings {
+    ($trait:ident { $($body:tt)* }) => {
+        $crate::validation::forward::forward_strings!(@impl ::std::ffi::OsString, as_os_str, $trait, { $($body)* });
+        $crate::validation::forward::forward_strings!(@impl ::std::path::Path, as_os_str, $trait, { $($body)* });
+        $crate::validation::forward::forward_strings!(@impl ::std::path::PathBuf, as_os_str, $trait, { $($body)* });
+        $crate::validation::forward::forward_strings!(@impl ::std::string::String, as_str, $trait, { $($body)* });
+    };
+    (
+        @impl $ty:ty, $as:ident, $trait:ident, {
+            $(fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)*) -> $ret:ty;)+
+        }
+    ) => {
+        impl $trait for $ty {
+            $(
+                fn $method(&self $(, $arg: $arg_ty)*) -> $ret {
+                    self.$as().$method($($arg),*)
+                }
+            )+
+        }
+    };
+}
+
+pub(crate) use forward_strings;
+pub(crate) use forward_wrappers;
@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+
+use crate::validation::{
+    Failure,
+    INVALID,
+    Validator,
+};
+
+// =================================================================================================
+// Function
+// =================================================================================================
+
+/// Adapts a predicate function to be a [`Validator`], producing a [`Failure`]
+/// with the generic `invalid` code and the given message when the predicate
+/// returns `false`.
+pub struct Predicate<F> {
+    message: Cow<'static, str>,
+    predicate: F,
+}
+
+impl<F> Predicate<F> {
+    /// Creates a new [`Predicate`] validator from the given predicate function
+    /// and failure message.
+    pub fn new<M>(predicate: F, message: M) -> Self
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        Self {
+            message: message.into(),
+            predicate,
+        }
+    }
+}
+
+impl<T, F> Validator<T> for Predicate<F>
+where
+    T: ?Sized,
+    F: Fn(&T) -> bool,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (!(self.predicate)(value)).then(|| Failure::new(INVALID, self.message.clone()))
+    }
+}
+
+/// Adapts a function returning an optional message to be a [`Validator`],
+/// producing a [`Failure`] with the generic `invalid` code and the returned
+/// message when the function returns `Some`.
+pub struct FromFn<F>(F);
+
+impl<F> FromFn<F> {
+    /// Creates a new [`FromFn`] validator from the given function.
+    pub fn new(function: F) -> Self {
+        Self(function)
+    }
+}
+
+impl<T, F> Validator<T> for FromFn<F>
+where
+    T: ?Sized,
+    F: Fn(&T) -> Option<String>,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (self.0)(value).map(|message| Failure::new(INVALID, message))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::{
+        assert_none,
+        assert_ok,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Error,
+        Failure,
+        NotEmpty,
+        Validator,
+        function::{
+            FromFn,
+            Predicate,
+        },
+        validate,
+    };
+
+    // Predicate
+
+    #[test]
+    fn predicate_valid() {
+        let validator = Predicate::new(|value: &i32| *value > 0, "not positive");
+
+        assert_none!(validator.validate(&1));
+    }
+
+    #[test]
+    fn predicate_invalid() {
+        let validator = Predicate::new(|value: &i32| *value > 0, "not positive");
+
+        assert_some_eq!(
+            Some(Failure::new("invalid", "not positive")),
+            validator.validate(&0)
+        );
+    }
+
+    #[test]
+    fn predicate_unsized() {
+        let validator = Predicate::new(|value: &str| value.is_ascii(), "not ascii");
+
+        assert_none!(validator.validate("name"));
+        assert_some_eq!(
+            Some(Failure::new("invalid", "not ascii")),
+            validator.validate("naïve")
+        );
+    }
+
+    #[test]
+    fn predicate_captures() {
+        let max = 100;
+        let validator = Predicate::new(
+            move |value: &i32| *value < max,
+            format!("not less than {max}"),
+        );
+
+        assert_some_eq!(
+            Some(Failure::new("invalid", "not less than 100")),
+            validator.validate(&100)
+        );
+    }
+
+    // FromFn
+
+    #[test]
+    fn from_fn_valid() {
+        let validator =
+            FromFn::new(|value: &i32| (*value > 10).then(|| format!("{value} too large")));
+
+        assert_none!(validator.validate(&10));
+    }
+
+    #[test]
+    fn from_fn_invalid() {
+        let validator =
+            FromFn::new(|value: &i32| (*value > 10).then(|| format!("{value} too large")));
+
+        assert_some_eq!(
+            Some(Failure::new("invalid", "11 too large")),
+            validator.validate(&11)
+        );
+    }
+
+    // Validate
+
+    #[test]
+    fn validate_inline() {
+        let value = String::from("name");
+
+        assert_ok!(validate(&value, "name", &[
+            &NotEmpty,
+            &Predicate::new(|value: &String| value.len() <= 8, "too long"),
+            &FromFn::new(|value: &String| value.starts_with('_').then(|| String::from("reserved"))),
+        ]));
+    }
+
+    #[test]
+    fn validate_inline_invalid() {
+        let value = String::from("_name");
+
+        assert_eq!(
+            validate(&value, "name", &[
+                &NotEmpty,
+                &Predicate::new(|value: &String| value.len() <= 8, "too long"),
+                &FromFn::new(|value: &String| value
+                    .starts_with('_')
+                    .then(|| String::from("reserved"))),
+            ]),
+            Err(Error::Invalid(
+                Failure::new("invalid", "reserved").with_prefix(&"name".into())
+            ))
+        );
+    }
+}
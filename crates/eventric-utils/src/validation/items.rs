@@ -0,0 +1,652 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        BinaryHeap,
+        HashMap,
+        HashSet,
+        LinkedList,
+        VecDeque,
+        hash_map::Entry,
+    },
+    fmt::Display,
+    hash::Hash,
+};
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Path,
+    Segment,
+    Validator,
+    Value,
+    forward::forward_wrappers,
+};
+
+// =================================================================================================
+// Items
+// =================================================================================================
+
+/// Validates that a collection has at least the given minimum number of items.
+#[derive(Clone, Copy, Debug)]
+pub struct MinItems(usize);
+
+impl MinItems {
+    /// Creates a new [`MinItems`] validator with the given minimum.
+    #[must_use]
+    pub fn new(min: usize) -> Self {
+        Self(min)
+    }
+}
+
+impl<T> Validator<T> for MinItems
+where
+    T: ItemsValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let actual = value.count_validation();
+
+        (actual < self.0).then(|| {
+            Failure::new(
+                "min_items",
+                format!("must have at least {} items, got {actual}", self.0),
+            )
+            .with_param("min", self.0)
+            .with_param("actual", actual)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for MinItems {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "minItems", self.0);
+        keyword(schema, "minProperties", self.0);
+    }
+}
+
+/// Validates that a collection has at most the given maximum number of items.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxItems(usize);
+
+impl MaxItems {
+    /// Creates a new [`MaxItems`] validator with the given maximum.
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        Self(max)
+    }
+}
+
+impl<T> Validator<T> for MaxItems
+where
+    T: ItemsValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let actual = value.count_validation();
+
+        (actual > self.0).then(|| {
+            Failure::new(
+                "max_items",
+                format!("must have at most {} items, got {actual}", self.0),
+            )
+            .with_param("max", self.0)
+            .with_param("actual", actual)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for MaxItems {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "maxItems", self.0);
+        keyword(schema, "maxProperties", self.0);
+    }
+}
+
+/// Validates that a collection has between the given minimum and maximum
+/// number of items (inclusive).
+#[derive(Clone, Copy, Debug)]
+pub struct ItemsRange {
+    max: usize,
+    min: usize,
+}
+
+impl ItemsRange {
+    /// Creates a new [`ItemsRange`] validator with the given minimum and
+    /// maximum.
+    #[must_use]
+    pub fn new(min: usize, max: usize) -> Self {
+        Self { max, min }
+    }
+}
+
+impl<T> Validator<T> for ItemsRange
+where
+    T: ItemsValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let actual = value.count_validation();
+
+        (actual < self.min || actual > self.max).then(|| {
+            Failure::new(
+                "items_range",
+                format!(
+                    "must have between {} and {} items, got {actual}",
+                    self.min, self.max
+                ),
+            )
+            .with_param("min", self.min)
+            .with_param("max", self.max)
+            .with_param("actual", actual)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for ItemsRange {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "minItems", self.min);
+        keyword(schema, "minProperties", self.min);
+        keyword(schema, "maxItems", self.max);
+        keyword(schema, "maxProperties", self.max);
+    }
+}
+
+/// Validates that the items of a collection are unique, reporting the first
+/// duplicate found. The failure path is prefixed by the index (in iteration
+/// order) of the duplicate, or by its key where the collection is a map, and
+/// the position is given as the `index` (or `key`) parameter. The position of
+/// the item it duplicates is given as the `first` parameter. Hash maps are
+/// visited in the order of their keys as strings. To compare items by a
+/// derived key rather than by the items themselves, see [`Unique::by`].
+#[derive(Clone, Copy, Debug)]
+pub struct Unique;
+
+impl Unique {
+    /// Creates a new [`UniqueBy`] validator, which compares items by the key
+    /// returned by the given function.
+    pub fn by<F>(key: F) -> UniqueBy<F> {
+        UniqueBy(key)
+    }
+}
+
+impl<T> Validator<T> for Unique
+where
+    T: ItemsValidation + ?Sized,
+    T::Item: Eq + Hash,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        duplicate(value.items_validation())
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for Unique {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "uniqueItems", true);
+    }
+}
+
+/// Validates that the items of a collection are unique by a derived key, as
+/// [`Unique`]. Created using [`Unique::by`].
+#[derive(Clone, Copy, Debug)]
+pub struct UniqueBy<F>(F);
+
+impl<T, F, K> Validator<T> for UniqueBy<F>
+where
+    T: ItemsValidation + ?Sized,
+    F: Fn(&T::Item) -> K,
+    K: Eq + Hash,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        duplicate(
+            value
+                .items_validation()
+                .map(|(segment, item)| (segment, (self.0)(item))),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Trait
+
+/// Supports validation by [`MinItems`], [`MaxItems`], [`ItemsRange`],
+/// [`Unique`], and [`UniqueBy`]. The items of a map are its values, identified
+/// by their keys.
+pub trait ItemsValidation {
+    /// The type of the items of the collection.
+    type Item;
+
+    /// Returns the number of items in the collection.
+    fn count_validation(&self) -> usize;
+
+    /// Returns an iterator over the items of the collection, each with the
+    /// path segment identifying it (its index, or its key within a map).
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)>;
+}
+
+impl<T, const N: usize> ItemsValidation for [T; N] {
+    type Item = T;
+
+    fn count_validation(&self) -> usize {
+        N
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        indexed(self.iter())
+    }
+}
+
+impl<T> ItemsValidation for [T] {
+    type Item = T;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        indexed(self.iter())
+    }
+}
+
+impl<T> ItemsValidation for BinaryHeap<T> {
+    type Item = T;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        indexed(self.iter())
+    }
+}
+
+impl<K, V> ItemsValidation for BTreeMap<K, V>
+where
+    K: Display,
+{
+    type Item = V;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        keyed(self.iter())
+    }
+}
+
+impl<T> ItemsValidation for BTreeSet<T> {
+    type Item = T;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        indexed(self.iter())
+    }
+}
+
+impl<K, V, S> ItemsValidation for HashMap<K, V, S>
+where
+    K: Display,
+{
+    type Item = V;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        keyed_sorted(self.iter())
+    }
+}
+
+impl<T, S> ItemsValidation for HashSet<T, S> {
+    type Item = T;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        indexed(self.iter())
+    }
+}
+
+impl<T> ItemsValidation for LinkedList<T> {
+    type Item = T;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        indexed(self.iter())
+    }
+}
+
+impl<T> ItemsValidation for Vec<T> {
+    type Item = T;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        indexed(self.iter())
+    }
+}
+
+impl<T> ItemsValidation for VecDeque<T> {
+    type Item = T;
+
+    fn count_validation(&self) -> usize {
+        self.len()
+    }
+
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)> {
+        indexed(self.iter())
+    }
+}
+
+forward_wrappers!(ItemsValidation {
+    type Item;
+    fn count_validation(&self) -> usize;
+    fn items_validation(&self) -> impl Iterator<Item = (Segment, &Self::Item)>;
+});
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Functions
+
+fn duplicate<I, K>(mut items: I) -> Option<Failure>
+where
+    I: Iterator<Item = (Segment, K)>,
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+
+    items.find_map(|(segment, key)| match seen.entry(key) {
+        Entry::Occupied(entry) => {
+            let (_, _, first) = position(entry.get());
+            let (path, name, value) = position(&segment);
+
+            Some(
+                Failure::new("duplicate", format!("duplicate of item {first}"))
+                    .with_path(path)
+                    .with_param(name, value)
+                    .with_param("first", first),
+            )
+        }
+        Entry::Vacant(entry) => {
+            entry.insert(segment);
+            None
+        }
+    })
+}
+
+fn indexed<'a, I, T>(items: I) -> impl Iterator<Item = (Segment, &'a T)>
+where
+    I: Iterator<Item = &'a T>,
+    T: 'a,
+{
+    items.enumerate().map(|(i, item)| (Segment::Index(i), item))
+}
+
+fn keyed<'a, I, K, T>(entries: I) -> impl Iterator<Item = (Segment, &'a T)>
+where
+    I: Iterator<Item = (&'a K, &'a T)>,
+    K: Display + 'a,
+    T: 'a,
+{
+    entries.map(|(key, item)| (Segment::Key(key.to_string()), item))
+}
+
+fn keyed_sorted<'a, I, K, T>(entries: I) -> impl Iterator<Item = (Segment, &'a T)>
+where
+    I: Iterator<Item = (&'a K, &'a T)>,
+    K: Display + 'a,
+    T: 'a,
+{
+    let mut entries = entries
+        .map(|(key, item)| (key.to_string(), item))
+        .collect::<Vec<_>>();
+
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries
+        .into_iter()
+        .map(|(key, item)| (Segment::Key(key), item))
+}
+
+fn position(segment: &Segment) -> (Path, &'static str, Value) {
+    match segment {
+        Segment::Field(field) => (
+            Path::new().field(field.clone()),
+            "field",
+            Value::String(field.clone()),
+        ),
+        Segment::Index(index) => (Path::new().index(*index), "index", Value::from(*index)),
+        Segment::Key(key) => (
+            Path::new().key(key.clone()),
+            "key",
+            Value::from(key.clone()),
+        ),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{
+            BTreeMap,
+            BTreeSet,
+            HashMap,
+            HashSet,
+            LinkedList,
+            VecDeque,
+        },
+        rc::Rc,
+    };
+
+    use assertables::{
+        assert_none,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Error,
+        Failure,
+        Validator as _,
+        items::{
+            ItemsRange,
+            MaxItems,
+            MinItems,
+            Unique,
+        },
+        validate,
+    };
+
+    // Test Types
+
+    #[derive(Debug)]
+    struct Tag {
+        name: String,
+        weight: u8,
+    }
+
+    fn tag(name: &str, weight: u8) -> Tag {
+        Tag {
+            name: name.to_owned(),
+            weight,
+        }
+    }
+
+    fn duplicate(index: usize, first: usize) -> Failure {
+        Failure::new("duplicate", format!("duplicate of item {first}"))
+            .with_path(format!("[{index}]"))
+            .with_param("index", index)
+            .with_param("first", first)
+    }
+
+    // Min Items
+
+    #[test]
+    fn min_items_valid() {
+        assert_none!(MinItems::new(2).validate(&vec![1, 2]));
+    }
+
+    #[test]
+    fn min_items_invalid() {
+        assert_some_eq!(
+            Some(
+                Failure::new("min_items", "must have at least 2 items, got 1")
+                    .with_param("min", 2_usize)
+                    .with_param("actual", 1_usize)
+            ),
+            MinItems::new(2).validate(&[1])
+        );
+    }
+
+    // Max Items
+
+    #[test]
+    fn max_items_valid() {
+        assert_none!(MaxItems::new(2).validate(&BTreeSet::from([1, 2])));
+    }
+
+    #[test]
+    fn max_items_invalid() {
+        assert_some_eq!(
+            Some(
+                Failure::new("max_items", "must have at most 2 items, got 3")
+                    .with_param("max", 2_usize)
+                    .with_param("actual", 3_usize)
+            ),
+            MaxItems::new(2).validate(&VecDeque::from([1, 2, 3]))
+        );
+    }
+
+    // Items Range
+
+    #[test]
+    fn items_range_valid() {
+        let validator = ItemsRange::new(1, 2);
+
+        assert_none!(validator.validate(&BTreeMap::from([(1, "a")])));
+        assert_none!(validator.validate(&HashSet::from([1, 2])));
+    }
+
+    #[test]
+    fn items_range_invalid() {
+        let validator = ItemsRange::new(1, 2);
+        let failure = |actual: usize| {
+            Failure::new(
+                "items_range",
+                format!("must have between 1 and 2 items, got {actual}"),
+            )
+            .with_param("min", 1_usize)
+            .with_param("max", 2_usize)
+            .with_param("actual", actual)
+        };
+
+        assert_some_eq!(
+            Some(failure(0)),
+            validator.validate(&LinkedList::<u8>::new())
+        );
+        assert_some_eq!(
+            Some(failure(3)),
+            validator.validate(&Rc::new(vec![1, 2, 3]))
+        );
+    }
+
+    // Unique
+
+    #[test]
+    fn unique_valid() {
+        assert_none!(Unique.validate(&vec!["a", "b", "c"]));
+        assert_none!(Unique.validate(&Vec::<u8>::new()));
+    }
+
+    #[test]
+    fn unique_invalid() {
+        assert_some_eq!(
+            Some(duplicate(3, 1)),
+            Unique.validate(&vec!["a", "b", "c", "b", "a"])
+        );
+    }
+
+    #[test]
+    fn unique_map_values_invalid() {
+        let value = BTreeMap::from([("x", 1), ("y", 2), ("z", 1)]);
+
+        assert_some_eq!(
+            Some(
+                Failure::new("duplicate", "duplicate of item x")
+                    .with_path("[\"z\"]")
+                    .with_param("key", "z")
+                    .with_param("first", "x")
+            ),
+            Unique.validate(&value)
+        );
+    }
+
+    #[test]
+    fn unique_hashmap_values_invalid() {
+        let value = HashMap::from([("c", 1), ("a", 1), ("d", 2), ("b", 1)]);
+
+        assert_some_eq!(
+            Some(
+                Failure::new("duplicate", "duplicate of item a")
+                    .with_path("[\"b\"]")
+                    .with_param("key", "b")
+                    .with_param("first", "a")
+            ),
+            Unique.validate(&value)
+        );
+    }
+
+    #[test]
+    fn unique_by_valid() {
+        let value = vec![tag("a", 1), tag("b", 1)];
+
+        assert_none!(Unique::by(|tag: &Tag| tag.name.clone()).validate(&value));
+    }
+
+    #[test]
+    fn unique_by_invalid() {
+        let value = vec![tag("a", 1), tag("b", 2), tag("c", 1)];
+
+        assert_some_eq!(
+            Some(duplicate(2, 0)),
+            Unique::by(|tag: &Tag| tag.weight).validate(&value)
+        );
+    }
+
+    // Validate
+
+    #[test]
+    fn validate_unique() {
+        let tags = vec![String::from("a"), String::from("b"), String::from("a")];
+
+        assert_eq!(
+            validate(&tags, "tags", &[&MaxItems::new(5), &Unique]),
+            Err(Error::Invalid(duplicate(2, 0).with_prefix(&"tags".into())))
+        );
+        assert_eq!(
+            validate(&tags, "tags", &[&Unique]).unwrap_err().to_string(),
+            "Validation Error: tags[2]: duplicate of item 0"
+        );
+    }
+}
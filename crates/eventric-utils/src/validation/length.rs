@@ -0,0 +1,607 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        BinaryHeap,
+        HashMap,
+        HashSet,
+        LinkedList,
+        VecDeque,
+    },
+    ffi::OsStr,
+};
+
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation as _;
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    forward::{
+        forward_strings,
+        forward_wrappers,
+    },
+};
+
+// =================================================================================================
+// Length
+// =================================================================================================
+
+/// The unit in which the length of a string value is counted. Collection
+/// values are always counted in elements, regardless of the unit. The set of
+/// units depends on the enabled features, so matches on it must include a
+/// wildcard arm.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LengthUnit {
+    /// UTF-8 encoded bytes.
+    Bytes,
+    /// Unicode scalar values (`char`s).
+    #[default]
+    Chars,
+    /// Extended grapheme clusters (user-perceived characters). Requires the
+    /// `unicode` feature.
+    #[cfg(feature = "unicode")]
+    Graphemes,
+}
+
+/// Validates that the length of a value is at least the given minimum.
+#[derive(Clone, Copy, Debug)]
+pub struct MinLength {
+    min: usize,
+    unit: LengthUnit,
+}
+
+impl MinLength {
+    /// Creates a new [`MinLength`] validator with the given minimum, counting
+    /// in [`LengthUnit::Chars`].
+    #[must_use]
+    pub fn new(min: usize) -> Self {
+        Self {
+            min,
+            unit: LengthUnit::default(),
+        }
+    }
+
+    /// Sets the [`LengthUnit`] used to count the length of string values.
+    #[must_use]
+    pub fn with_unit(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+impl<T> Validator<T> for MinLength
+where
+    T: LengthValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let actual = value.length_validation(self.unit);
+
+        (actual < self.min).then(|| {
+            Failure::new(
+                "min_length",
+                format!("length must be at least {}, got {actual}", self.min),
+            )
+            .with_param("min", self.min)
+            .with_param("actual", actual)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for MinLength {
+    fn describe(&self, schema: &mut Schema) {
+        if self.unit == LengthUnit::Chars {
+            keyword(schema, "minLength", self.min);
+        }
+
+        keyword(schema, "minItems", self.min);
+        keyword(schema, "minProperties", self.min);
+    }
+}
+
+/// Validates that the length of a value is at most the given maximum.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxLength {
+    max: usize,
+    unit: LengthUnit,
+}
+
+impl MaxLength {
+    /// Creates a new [`MaxLength`] validator with the given maximum, counting
+    /// in [`LengthUnit::Chars`].
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        Self {
+            max,
+            unit: LengthUnit::default(),
+        }
+    }
+
+    /// Sets the [`LengthUnit`] used to count the length of string values.
+    #[must_use]
+    pub fn with_unit(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+impl<T> Validator<T> for MaxLength
+where
+    T: LengthValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let actual = value.length_validation(self.unit);
+
+        (actual > self.max).then(|| {
+            Failure::new(
+                "max_length",
+                format!("length must be at most {}, got {actual}", self.max),
+            )
+            .with_param("max", self.max)
+            .with_param("actual", actual)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for MaxLength {
+    fn describe(&self, schema: &mut Schema) {
+        if self.unit == LengthUnit::Chars {
+            keyword(schema, "maxLength", self.max);
+        }
+
+        keyword(schema, "maxItems", self.max);
+        keyword(schema, "maxProperties", self.max);
+    }
+}
+
+/// Validates that the length of a value is between the given minimum and
+/// maximum (inclusive).
+#[derive(Clone, Copy, Debug)]
+pub struct LengthRange {
+    max: usize,
+    min: usize,
+    unit: LengthUnit,
+}
+
+impl LengthRange {
+    /// Creates a new [`LengthRange`] validator with the given minimum and
+    /// maximum, counting in [`LengthUnit::Chars`].
+    #[must_use]
+    pub fn new(min: usize, max: usize) -> Self {
+        Self {
+            max,
+            min,
+            unit: LengthUnit::default(),
+        }
+    }
+
+    /// Sets the [`LengthUnit`] used to count the length of string values.
+    #[must_use]
+    pub fn with_unit(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+impl<T> Validator<T> for LengthRange
+where
+    T: LengthValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let actual = value.length_validation(self.unit);
+
+        (actual < self.min || actual > self.max).then(|| {
+            Failure::new(
+                "length_range",
+                format!(
+                    "length must be between {} and {}, got {actual}",
+                    self.min, self.max
+                ),
+            )
+            .with_param("min", self.min)
+            .with_param("max", self.max)
+            .with_param("actual", actual)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for LengthRange {
+    fn describe(&self, schema: &mut Schema) {
+        if self.unit == LengthUnit::Chars {
+            keyword(schema, "minLength", self.min);
+            keyword(schema, "maxLength", self.max);
+        }
+
+        keyword(schema, "minItems", self.min);
+        keyword(schema, "maxItems", self.max);
+        keyword(schema, "minProperties", self.min);
+        keyword(schema, "maxProperties", self.max);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Trait
+
+/// Supports validation by [`MinLength`], [`MaxLength`], and [`LengthRange`].
+/// Collections report their element count whatever the unit, while strings and
+/// paths are counted in the requested unit (paths lossily, beyond bytes).
+pub trait LengthValidation {
+    /// Returns the length of the value, counted in the given [`LengthUnit`]
+    /// where the value is string-like.
+    fn length_validation(&self, unit: LengthUnit) -> usize;
+}
+
+impl<T, const N: usize> LengthValidation for [T; N] {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        N
+    }
+}
+
+impl<T> LengthValidation for [T] {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+impl<T> LengthValidation for BinaryHeap<T> {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+impl<T, U> LengthValidation for BTreeMap<T, U> {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+impl<T> LengthValidation for BTreeSet<T> {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+impl<T, U, S> LengthValidation for HashMap<T, U, S> {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> LengthValidation for HashSet<T, S> {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+impl<T> LengthValidation for LinkedList<T> {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+impl LengthValidation for OsStr {
+    fn length_validation(&self, unit: LengthUnit) -> usize {
+        if unit == LengthUnit::Bytes {
+            self.as_encoded_bytes().len()
+        } else {
+            self.to_string_lossy().length_validation(unit)
+        }
+    }
+}
+
+impl LengthValidation for str {
+    fn length_validation(&self, unit: LengthUnit) -> usize {
+        match unit {
+            LengthUnit::Bytes => self.len(),
+            LengthUnit::Chars => self.chars().count(),
+            #[cfg(feature = "unicode")]
+            LengthUnit::Graphemes => self.graphemes(true).count(),
+        }
+    }
+}
+
+impl<T> LengthValidation for Vec<T> {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+impl<T> LengthValidation for VecDeque<T> {
+    fn length_validation(&self, _: LengthUnit) -> usize {
+        self.len()
+    }
+}
+
+forward_strings!(LengthValidation {
+    fn length_validation(&self, unit: LengthUnit) -> usize;
+});
+
+forward_wrappers!(LengthValidation {
+    fn length_validation(&self, unit: LengthUnit) -> usize;
+});
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::Cow,
+        collections::{
+            BTreeMap,
+            HashSet,
+        },
+        ffi::OsString,
+        path::PathBuf,
+        rc::Rc,
+        sync::Arc,
+    };
+
+    use assertables::{
+        assert_none,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Failure,
+        Validator as _,
+        length::{
+            LengthRange,
+            LengthUnit,
+            LengthValidation,
+            MaxLength,
+            MinLength,
+        },
+    };
+
+    // Test Helpers
+
+    fn min_length(min: usize, actual: usize) -> Failure {
+        Failure::new(
+            "min_length",
+            format!("length must be at least {min}, got {actual}"),
+        )
+        .with_param("min", min)
+        .with_param("actual", actual)
+    }
+
+    fn max_length(max: usize, actual: usize) -> Failure {
+        Failure::new(
+            "max_length",
+            format!("length must be at most {max}, got {actual}"),
+        )
+        .with_param("max", max)
+        .with_param("actual", actual)
+    }
+
+    fn length_range(min: usize, max: usize, actual: usize) -> Failure {
+        Failure::new(
+            "length_range",
+            format!("length must be between {min} and {max}, got {actual}"),
+        )
+        .with_param("min", min)
+        .with_param("max", max)
+        .with_param("actual", actual)
+    }
+
+    // Length Unit
+
+    #[test]
+    fn length_unit_str() {
+        let value = "ne\u{301}e 👍🏽";
+
+        assert_eq!(14, value.length_validation(LengthUnit::Bytes));
+        assert_eq!(7, value.length_validation(LengthUnit::Chars));
+        #[cfg(feature = "unicode")]
+        assert_eq!(5, value.length_validation(LengthUnit::Graphemes));
+    }
+
+    #[test]
+    fn length_unit_default_chars() {
+        assert_eq!(LengthUnit::Chars, LengthUnit::default());
+    }
+
+    // Min Length
+
+    #[test]
+    fn min_length_valid() {
+        let validator = MinLength::new(3);
+
+        assert_none!(validator.validate("abc"));
+    }
+
+    #[test]
+    fn min_length_invalid() {
+        let validator = MinLength::new(3);
+
+        assert_some_eq!(Some(min_length(3, 2)), validator.validate("ab"));
+    }
+
+    #[test]
+    fn min_length_counts_chars_by_default() {
+        let validator = MinLength::new(4);
+
+        assert_some_eq!(Some(min_length(4, 3)), validator.validate("äöü"));
+    }
+
+    #[test]
+    fn min_length_bytes() {
+        let validator = MinLength::new(4).with_unit(LengthUnit::Bytes);
+
+        assert_none!(validator.validate("äöü"));
+        assert_some_eq!(Some(min_length(4, 3)), validator.validate("abc"));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn min_length_graphemes() {
+        let validator = MinLength::new(2).with_unit(LengthUnit::Graphemes);
+
+        assert_some_eq!(Some(min_length(2, 1)), validator.validate("e\u{301}"));
+        assert_none!(validator.validate("e\u{301}e"));
+    }
+
+    // Max Length
+
+    #[test]
+    fn max_length_valid() {
+        let validator = MaxLength::new(3);
+
+        assert_none!(validator.validate("abc"));
+    }
+
+    #[test]
+    fn max_length_invalid() {
+        let validator = MaxLength::new(3);
+
+        assert_some_eq!(Some(max_length(3, 4)), validator.validate("abcd"));
+    }
+
+    #[test]
+    fn max_length_bytes() {
+        let validator = MaxLength::new(3).with_unit(LengthUnit::Bytes);
+
+        assert_some_eq!(Some(max_length(3, 6)), validator.validate("äöü"));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn max_length_graphemes() {
+        let validator = MaxLength::new(1).with_unit(LengthUnit::Graphemes);
+
+        assert_none!(validator.validate("👍🏽"));
+        assert_some_eq!(Some(max_length(1, 2)), MaxLength::new(1).validate("👍🏽"));
+    }
+
+    // Length Range
+
+    #[test]
+    fn length_range_valid() {
+        let validator = LengthRange::new(2, 4);
+
+        assert_none!(validator.validate("ab"));
+        assert_none!(validator.validate("abcd"));
+    }
+
+    #[test]
+    fn length_range_invalid_short() {
+        let validator = LengthRange::new(2, 4);
+
+        assert_some_eq!(Some(length_range(2, 4, 1)), validator.validate("a"));
+    }
+
+    #[test]
+    fn length_range_invalid_long() {
+        let validator = LengthRange::new(2, 4);
+
+        assert_some_eq!(Some(length_range(2, 4, 5)), validator.validate("abcde"));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn length_range_graphemes() {
+        let validator = LengthRange::new(1, 1).with_unit(LengthUnit::Graphemes);
+
+        assert_none!(validator.validate("e\u{301}"));
+    }
+
+    // Other Types
+
+    #[test]
+    fn length_arc_str() {
+        let value: Arc<str> = Arc::from("abcd");
+
+        assert_some_eq!(Some(max_length(3, 4)), MaxLength::new(3).validate(&value));
+    }
+
+    #[test]
+    fn length_cow_str() {
+        let value: Cow<'_, str> = Cow::Borrowed("ab");
+
+        assert_some_eq!(Some(min_length(3, 2)), MinLength::new(3).validate(&value));
+    }
+
+    #[test]
+    fn length_osstring() {
+        let value = OsString::from("abcd");
+
+        assert_some_eq!(Some(max_length(3, 4)), MaxLength::new(3).validate(&value));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn length_osstr_non_utf8_bytes() {
+        use std::{
+            ffi::OsStr,
+            os::unix::ffi::OsStrExt as _,
+        };
+
+        let value = OsStr::from_bytes(b"a\xff");
+
+        assert_eq!(2, value.length_validation(LengthUnit::Bytes));
+        assert_eq!(2, value.length_validation(LengthUnit::Chars));
+    }
+
+    #[test]
+    fn length_pathbuf() {
+        let value = PathBuf::from("a/b");
+
+        assert_none!(LengthRange::new(3, 3).validate(&value));
+    }
+
+    #[test]
+    fn length_rc_string() {
+        let value = Rc::new(String::from("ab"));
+
+        assert_some_eq!(Some(min_length(3, 2)), MinLength::new(3).validate(&value));
+    }
+
+    #[test]
+    fn length_vec() {
+        let value = vec![String::from("äöü"); 2];
+
+        assert_none!(MaxLength::new(2).validate(&value));
+        assert_some_eq!(
+            Some(max_length(1, 2)),
+            MaxLength::new(1)
+                .with_unit(LengthUnit::Bytes)
+                .validate(&value)
+        );
+    }
+
+    #[test]
+    fn length_array() {
+        let value = [1, 2, 3];
+
+        assert_some_eq!(
+            Some(length_range(1, 2, 3)),
+            LengthRange::new(1, 2).validate(&value)
+        );
+    }
+
+    #[test]
+    fn length_btreemap() {
+        let value = BTreeMap::from_iter([(1, "one")]);
+
+        assert_some_eq!(Some(min_length(2, 1)), MinLength::new(2).validate(&value));
+    }
+
+    #[test]
+    fn length_hashset() {
+        let value = HashSet::from([1, 2]);
+
+        assert_none!(LengthRange::new(1, 2).validate(&value));
+    }
+}
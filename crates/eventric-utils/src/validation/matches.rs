@@ -0,0 +1,257 @@
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+};
+
+use regex::Regex;
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    forward::{
+        forward_strings,
+        forward_wrappers,
+    },
+};
+
+// =================================================================================================
+// Matches
+// =================================================================================================
+
+/// Validates that a value matches a regular expression, either in full (the
+/// whole value must match) or by containing a match anywhere in the value. The
+/// pattern is compiled once, when the validator is created, so validators
+/// should be created once and reused (for example in a `static`).
+#[derive(Clone, Debug)]
+pub struct Matches {
+    message: Cow<'static, str>,
+    pattern: String,
+    regex: Regex,
+}
+
+impl Matches {
+    /// Creates a new [`Matches`] validator which requires the whole value to
+    /// match the given pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not a valid regular expression.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Self::compile(pattern, &format!(r"\A(?:{pattern})\z"))
+    }
+
+    /// Creates a new [`Matches`] validator which requires the value to contain
+    /// a match for the given pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not a valid regular expression.
+    pub fn contains(pattern: &str) -> Result<Self, regex::Error> {
+        Self::compile(pattern, pattern)
+    }
+
+    /// Sets the failure message, replacing the default message.
+    #[must_use]
+    pub fn with_message<M>(mut self, message: M) -> Self
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        self.message = message.into();
+        self
+    }
+
+    fn compile(pattern: &str, regex: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            message: Cow::Owned(format!("does not match pattern {pattern}")),
+            pattern: pattern.to_owned(),
+            regex: Regex::new(regex)?,
+        })
+    }
+}
+
+impl<T> Validator<T> for Matches
+where
+    T: MatchesValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (!value.matches_validation(&self.regex)).then(|| {
+            Failure::new("pattern", self.message.clone())
+                .with_param("pattern", self.pattern.clone())
+        })
+    }
+}
+
+/// Patterns are given as written, and so should use syntax common to Rust and
+/// ECMA-262 regular expressions if they are to be used by other systems.
+#[cfg(feature = "schema")]
+impl Describe for Matches {
+    fn describe(&self, schema: &mut Schema) {
+        if self.regex.as_str() == self.pattern {
+            keyword(schema, "pattern", self.pattern.as_str());
+        } else {
+            keyword(schema, "pattern", format!("^(?:{})$", self.pattern));
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Trait
+
+/// Supports validation by [`Matches`]. Non-UTF-8 values are matched after
+/// lossy conversion.
+pub trait MatchesValidation {
+    /// Returns `true` if the value contains a match for the given regular
+    /// expression.
+    fn matches_validation(&self, regex: &Regex) -> bool;
+}
+
+impl MatchesValidation for OsStr {
+    fn matches_validation(&self, regex: &Regex) -> bool {
+        regex.is_match(&self.to_string_lossy())
+    }
+}
+
+impl MatchesValidation for str {
+    fn matches_validation(&self, regex: &Regex) -> bool {
+        regex.is_match(self)
+    }
+}
+
+forward_strings!(MatchesValidation {
+    fn matches_validation(&self, regex: &Regex) -> bool;
+});
+
+forward_wrappers!(MatchesValidation {
+    fn matches_validation(&self, regex: &Regex) -> bool;
+});
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ffi::OsString,
+        sync::{
+            Arc,
+            LazyLock,
+        },
+    };
+
+    use assertables::{
+        assert_err,
+        assert_none,
+        assert_ok,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Failure,
+        Validator as _,
+        matches::Matches,
+        validate,
+    };
+
+    // Test Helpers
+
+    static EVENT_TYPE: LazyLock<Matches> = LazyLock::new(|| {
+        Matches::new(r"[a-z]+(\.[a-z]+)*")
+            .unwrap()
+            .with_message("must be a dotted lowercase name")
+    });
+
+    fn mismatch(pattern: &str) -> Failure {
+        Failure::new("pattern", format!("does not match pattern {pattern}"))
+            .with_param("pattern", pattern.to_owned())
+    }
+
+    // Matches
+
+    #[test]
+    fn matches_invalid_pattern() {
+        assert_err!(Matches::new("[a-z"));
+        assert_err!(Matches::contains("(unclosed"));
+    }
+
+    #[test]
+    fn matches_full_valid() {
+        let validator = Matches::new("[a-z]+").unwrap();
+
+        assert_none!(validator.validate("abc"));
+    }
+
+    #[test]
+    fn matches_full_invalid_partial() {
+        let validator = Matches::new("[a-z]+").unwrap();
+
+        assert_some_eq!(Some(mismatch("[a-z]+")), validator.validate("abc1"));
+    }
+
+    #[test]
+    fn matches_full_alternation() {
+        let validator = Matches::new("a|ab").unwrap();
+
+        assert_none!(validator.validate("ab"));
+        assert_some_eq!(Some(mismatch("a|ab")), validator.validate("abc"));
+    }
+
+    #[test]
+    fn matches_contains_valid() {
+        let validator = Matches::contains("[0-9]").unwrap();
+
+        assert_none!(validator.validate("abc1"));
+    }
+
+    #[test]
+    fn matches_contains_invalid() {
+        let validator = Matches::contains("[0-9]").unwrap();
+
+        assert_some_eq!(Some(mismatch("[0-9]")), validator.validate("abc"));
+    }
+
+    #[test]
+    fn matches_custom_message() {
+        assert_none!(EVENT_TYPE.validate("order.placed"));
+        assert_some_eq!(
+            Some(
+                Failure::new("pattern", "must be a dotted lowercase name")
+                    .with_param("pattern", r"[a-z]+(\.[a-z]+)*")
+            ),
+            EVENT_TYPE.validate("Order.Placed")
+        );
+    }
+
+    #[test]
+    fn matches_validate() {
+        let value = String::from("order.placed");
+
+        assert_ok!(validate(&value, "event_type", &[&*EVENT_TYPE]));
+    }
+
+    // Other Types
+
+    #[test]
+    fn matches_arc_str() {
+        let value: Arc<str> = Arc::from("abc");
+
+        assert_none!(Matches::new("[a-c]+").unwrap().validate(&value));
+    }
+
+    #[test]
+    fn matches_osstring() {
+        let value = OsString::from("ab-1");
+
+        assert_some_eq!(
+            Some(mismatch("[a-z]+")),
+            Matches::new("[a-z]+").unwrap().validate(&value)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::validation::{
+    Failure,
+    INVALID,
+    MessageValidator,
+    Validator,
+};
+
+// =================================================================================================
+// Message
+// =================================================================================================
+
+/// Adapts a [`MessageValidator`] to be a [`Validator`], producing a
+/// [`Failure`] with the generic `invalid` code and the returned message.
+pub struct Message<V>(pub V);
+
+impl<T, V> Validator<T> for Message<V>
+where
+    T: ?Sized,
+    V: MessageValidator<T>,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        self.0
+            .validate(value)
+            .map(|message| Failure::new(INVALID, message.to_owned()))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::{
+        assert_none,
+        assert_ok,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Error,
+        Failure,
+        MessageValidator,
+        NotEmpty,
+        Validator,
+        message::Message,
+        validate,
+    };
+
+    // Test Validators
+
+    struct IsOdd;
+
+    impl MessageValidator<i32> for IsOdd {
+        fn validate(&self, value: &i32) -> Option<&str> {
+            (value % 2 == 0).then_some("not odd")
+        }
+    }
+
+    struct NoDigits;
+
+    impl MessageValidator<String> for NoDigits {
+        fn validate(&self, value: &String) -> Option<&str> {
+            value
+                .contains(|c: char| c.is_ascii_digit())
+                .then_some("digits")
+        }
+    }
+
+    // Message
+
+    #[test]
+    fn message_valid() {
+        let validator = Message(IsOdd);
+
+        assert_none!(validator.validate(&3));
+    }
+
+    #[test]
+    fn message_invalid() {
+        let validator = Message(IsOdd);
+
+        assert_some_eq!(
+            Some(Failure::new("invalid", "not odd")),
+            validator.validate(&4)
+        );
+    }
+
+    #[test]
+    fn message_in_validators() {
+        let validators: &[&dyn Validator<i32>] = &[&Message(IsOdd)];
+
+        assert_ok!(validate(&3, "number", validators));
+        assert_eq!(
+            validate(&4, "number", validators),
+            Err(Error::Invalid(
+                Failure::new("invalid", "not odd").with_path("number")
+            ))
+        );
+    }
+
+    #[test]
+    fn message_mixed_with_validators() {
+        let validators: &[&dyn Validator<String>] = &[&NotEmpty, &Message(NoDigits)];
+
+        assert_eq!(
+            validate(&String::new(), "text", validators),
+            Err(Error::Invalid(
+                Failure::new("empty", "empty").with_path("text")
+            ))
+        );
+        assert_eq!(
+            validate(&String::from("a1"), "text", validators),
+            Err(Error::Invalid(
+                Failure::new("invalid", "digits").with_path("text")
+            ))
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use crate::validation::{
+    AsyncValidator,
+    ContextValidator,
+    Error,
+    Failure,
+    Path,
+    Report,
+    Validator,
+    validate,
+    validate_all,
+    validate_all_async,
+    validate_all_async_reported,
+    validate_all_reported,
+    validate_all_with,
+    validate_all_with_reported,
+    validate_async,
+    validate_async_reported,
+    validate_reported,
+    validate_with,
+    validate_with_reported,
+};
+
+// =================================================================================================
+// Mode
+// =================================================================================================
+
+/// The [`Mode`] in which a validation is run, determining whether validation
+/// stops at the first failure or continues to collect all failures.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Mode {
+    /// Validation stops at the first failure, returning an [`Error::Invalid`]
+    /// error, as with [`validate`].
+    #[default]
+    FailFast,
+    /// Validation continues after failures, returning an [`Error::InvalidAll`]
+    /// error containing all failures, as with [`validate_all`].
+    All,
+}
+
+impl Mode {
+    /// Validates a given value in this mode, using either [`validate`] or
+    /// [`validate_all`] as appropriate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation fails, as described for [`validate`]
+    /// and [`validate_all`].
+    pub fn validate<T, N>(
+        self,
+        value: &T,
+        name: N,
+        validators: &[&dyn Validator<T>],
+    ) -> Result<(), Error>
+    where
+        T: ?Sized,
+        N: Into<Path>,
+    {
+        match self {
+            Self::FailFast => validate(value, name, validators),
+            Self::All => validate_all(value, name, validators),
+        }
+    }
+
+    /// Validates a given value in this mode, collecting any advisory failures
+    /// in the given [`Report`], using either [`validate_reported`] or
+    /// [`validate_all_reported`] as appropriate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation fails, as described for
+    /// [`validate_reported`] and [`validate_all_reported`].
+    pub fn validate_reported<T, N>(
+        self,
+        value: &T,
+        name: N,
+        validators: &[&dyn Validator<T>],
+        report: &mut Report,
+    ) -> Result<(), Error>
+    where
+        T: ?Sized,
+        N: Into<Path>,
+    {
+        match self {
+            Self::FailFast => validate_reported(value, name, validators, report),
+            Self::All => validate_all_reported(value, name, validators, report),
+        }
+    }
+
+    /// Validates a given value in a given context in this mode, using either
+    /// [`validate_with`] or [`validate_all_with`] as appropriate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation fails, as described for
+    /// [`validate_with`] and [`validate_all_with`].
+    pub fn validate_with<T, C, N>(
+        self,
+        value: &T,
+        context: &C,
+        name: N,
+        validators: &[&dyn ContextValidator<T, C>],
+    ) -> Result<(), Error>
+    where
+        T: ?Sized,
+        C: ?Sized,
+        N: Into<Path>,
+    {
+        match self {
+            Self::FailFast => validate_with(value, context, name, validators),
+            Self::All => validate_all_with(value, context, name, validators),
+        }
+    }
+
+    /// Validates a given value in a given context in this mode, collecting any
+    /// advisory failures in the given [`Report`], using either
+    /// [`validate_with_reported`] or [`validate_all_with_reported`] as
+    /// appropriate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation fails, as described for
+    /// [`validate_with_reported`] and [`validate_all_with_reported`].
+    pub fn validate_with_reported<T, C, N>(
+        self,
+        value: &T,
+        context: &C,
+        name: N,
+        validators: &[&dyn ContextValidator<T, C>],
+        report: &mut Report,
+    ) -> Result<(), Error>
+    where
+        T: ?Sized,
+        C: ?Sized,
+        N: Into<Path>,
+    {
+        match self {
+            Self::FailFast => validate_with_reported(value, context, name, validators, report),
+            Self::All => validate_all_with_reported(value, context, name, validators, report),
+        }
+    }
+
+    /// Validates a given value asynchronously in this mode, using either
+    /// [`validate_async`] or [`validate_all_async`] as appropriate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation fails, as described for
+    /// [`validate_async`] and [`validate_all_async`].
+    pub async fn validate_async<T, N>(
+        self,
+        value: &T,
+        name: N,
+        validators: &[&dyn AsyncValidator<T>],
+    ) -> Result<(), Error>
+    where
+        T: Sync + ?Sized,
+        N: Into<Path>,
+    {
+        match self {
+            Self::FailFast => validate_async(value, name, validators).await,
+            Self::All => validate_all_async(value, name, validators).await,
+        }
+    }
+
+    /// Validates a given value asynchronously in this mode, collecting any
+    /// advisory failures in the given [`Report`], using either
+    /// [`validate_async_reported`] or [`validate_all_async_reported`] as
+    /// appropriate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when validation fails, as described for
+    /// [`validate_async_reported`] and [`validate_all_async_reported`].
+    pub async fn validate_async_reported<T, N>(
+        self,
+        value: &T,
+        name: N,
+        validators: &[&dyn AsyncValidator<T>],
+        report: &mut Report,
+    ) -> Result<(), Error>
+    where
+        T: Sync + ?Sized,
+        N: Into<Path>,
+    {
+        match self {
+            Self::FailFast => validate_async_reported(value, name, validators, report).await,
+            Self::All => validate_all_async_reported(value, name, validators, report).await,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Collector
+
+/// Collects the results of multiple validations in a given [`Mode`], such as
+/// the validations of each field of a struct. In [`Mode::FailFast`] the first
+/// error is returned immediately from [`Collector::collect`], while in
+/// [`Mode::All`] errors are collected and returned together from
+/// [`Collector::finish`].
+#[derive(Debug)]
+pub struct Collector {
+    failures: Vec<Failure>,
+    mode: Mode,
+}
+
+impl Collector {
+    /// Creates a new [`Collector`] for the given [`Mode`].
+    #[must_use]
+    pub fn new(mode: Mode) -> Self {
+        Self {
+            failures: Vec::new(),
+            mode,
+        }
+    }
+
+    /// Returns the [`Mode`] of the collector.
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Collects the result of a validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the given error immediately when the collector is in
+    /// [`Mode::FailFast`], otherwise collects the failures of the error for
+    /// return from [`Collector::finish`].
+    pub fn collect(&mut self, result: Result<(), Error>) -> Result<(), Error> {
+        match (result, self.mode) {
+            (Ok(()), _) => Ok(()),
+            (Err(error), Mode::FailFast) => Err(error),
+            (Err(error), Mode::All) => {
+                self.failures.extend(error.into_failures());
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Finishes collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidAll`] error containing all collected failures
+    /// if any failures were collected.
+    pub fn finish(self) -> Result<(), Error> {
+        if self.failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidAll(self.failures))
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::{
+        assert_err,
+        assert_ok,
+    };
+
+    use crate::validation::{
+        Error,
+        Failure,
+        NoWhiteSpace,
+        NotEmpty,
+        Validator,
+        mode::{
+            Collector,
+            Mode,
+        },
+    };
+
+    // Mode
+
+    #[test]
+    fn mode_fail_fast_validate() {
+        let validators: &[&dyn Validator<str>] = &[&NotEmpty, &NoWhiteSpace];
+
+        assert_ok!(Mode::FailFast.validate("name", "name", validators));
+        assert_eq!(
+            Mode::FailFast.validate("", "name", validators),
+            Err(Error::Invalid(
+                Failure::new("empty", "empty").with_path("name")
+            ))
+        );
+    }
+
+    #[test]
+    fn mode_all_validate() {
+        let validators: &[&dyn Validator<str>] = &[&NotEmpty, &NoWhiteSpace];
+
+        assert_ok!(Mode::All.validate("name", "name", validators));
+        assert_eq!(
+            Mode::All.validate("a b", "name", validators),
+            Err(Error::InvalidAll(vec![
+                Failure::new("whitespace", "whitespace").with_path("name")
+            ]))
+        );
+    }
+
+    // Collector
+
+    #[test]
+    fn collector_valid() {
+        let mut collector = Collector::new(Mode::All);
+
+        assert_ok!(collector.collect(Ok(())));
+        assert_ok!(collector.finish());
+    }
+
+    #[test]
+    fn collector_fail_fast_returns_first_error() {
+        let mut collector = Collector::new(Mode::FailFast);
+
+        assert_ok!(collector.collect(Ok(())));
+        assert_eq!(
+            assert_err!(collector.collect(Err(Error::invalid("first")))),
+            Error::invalid("first")
+        );
+    }
+
+    #[test]
+    fn collector_all_collects_errors() {
+        let mut collector = Collector::new(Mode::All);
+
+        assert_ok!(collector.collect(Err(Error::invalid("first"))));
+        assert_ok!(collector.collect(Ok(())));
+        assert_ok!(collector.collect(Err(Error::invalid_all(["second", "third"]))));
+        assert_eq!(
+            collector.finish(),
+            Err(Error::invalid_all(["first", "second", "third"]))
+        );
+    }
+}
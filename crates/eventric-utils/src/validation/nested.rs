@@ -0,0 +1,459 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        VecDeque,
+    },
+    fmt::Display,
+};
+
+use crate::validation::{
+    Collector,
+    Error,
+    Mode,
+    Path,
+    Report,
+    ValidateNested,
+    forward::forward_wrappers,
+    validate_nested_reported,
+};
+
+// =================================================================================================
+// Nested
+// =================================================================================================
+
+// Wrappers
+
+impl<T> ValidateNested for Option<T>
+where
+    T: ValidateNested,
+{
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+        self.validate_nested_reported(mode, &mut Report::new())
+    }
+
+    fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error> {
+        self.as_ref()
+            .map_or(Ok(()), |value| value.validate_nested_reported(mode, report))
+    }
+}
+
+forward_wrappers!(ValidateNested {
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error>;
+    fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error>;
+});
+
+// Sequences
+
+impl<T, const N: usize> ValidateNested for [T; N]
+where
+    T: ValidateNested,
+{
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+        self.validate_nested_reported(mode, &mut Report::new())
+    }
+
+    fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error> {
+        validate_indexed(self.iter(), mode, report)
+    }
+}
+
+impl<T> ValidateNested for [T]
+where
+    T: ValidateNested,
+{
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+        self.validate_nested_reported(mode, &mut Report::new())
+    }
+
+    fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error> {
+        validate_indexed(self.iter(), mode, report)
+    }
+}
+
+impl<T> ValidateNested for Vec<T>
+where
+    T: ValidateNested,
+{
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+        self.validate_nested_reported(mode, &mut Report::new())
+    }
+
+    fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error> {
+        validate_indexed(self.iter(), mode, report)
+    }
+}
+
+impl<T> ValidateNested for VecDeque<T>
+where
+    T: ValidateNested,
+{
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+        self.validate_nested_reported(mode, &mut Report::new())
+    }
+
+    fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error> {
+        validate_indexed(self.iter(), mode, report)
+    }
+}
+
+// Maps
+
+impl<K, V> ValidateNested for BTreeMap<K, V>
+where
+    K: Display,
+    V: ValidateNested,
+{
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+        self.validate_nested_reported(mode, &mut Report::new())
+    }
+
+    fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error> {
+        validate_keyed(self.iter(), mode, report)
+    }
+}
+
+impl<K, V, S> ValidateNested for HashMap<K, V, S>
+where
+    K: Display,
+    V: ValidateNested,
+{
+    fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+        self.validate_nested_reported(mode, &mut Report::new())
+    }
+
+    fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error> {
+        validate_keyed_sorted(self.iter(), mode, report)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Functions
+
+fn validate_indexed<'a, I, T>(values: I, mode: Mode, report: &mut Report) -> Result<(), Error>
+where
+    I: Iterator<Item = &'a T>,
+    T: ValidateNested + 'a,
+{
+    let mut collector = Collector::new(mode);
+
+    for (i, value) in values.enumerate() {
+        collector.collect(validate_nested_reported(
+            value,
+            Path::new().index(i),
+            mode,
+            report,
+        ))?;
+    }
+
+    collector.finish()
+}
+
+fn validate_keyed<'a, I, K, V>(entries: I, mode: Mode, report: &mut Report) -> Result<(), Error>
+where
+    I: Iterator<Item = (&'a K, &'a V)>,
+    K: Display + 'a,
+    V: ValidateNested + 'a,
+{
+    let mut collector = Collector::new(mode);
+
+    for (key, value) in entries {
+        collector.collect(validate_nested_reported(
+            value,
+            Path::new().key(key.to_string()),
+            mode,
+            report,
+        ))?;
+    }
+
+    collector.finish()
+}
+
+fn validate_keyed_sorted<'a, I, K, V>(
+    entries: I,
+    mode: Mode,
+    report: &mut Report,
+) -> Result<(), Error>
+where
+    I: Iterator<Item = (&'a K, &'a V)>,
+    K: Display + 'a,
+    V: ValidateNested + 'a,
+{
+    let mut entries = entries
+        .map(|(key, value)| (key.to_string(), value))
+        .collect::<Vec<_>>();
+
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    validate_keyed(
+        entries.iter().map(|(key, value)| (key, *value)),
+        mode,
+        report,
+    )
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{
+            BTreeMap,
+            HashMap,
+        },
+        rc::Rc,
+    };
+
+    use assertables::assert_ok;
+
+    use crate::validation::{
+        Advisory,
+        Collector,
+        Error,
+        Failure,
+        Mode,
+        NoWhiteSpace,
+        NotEmpty,
+        Report,
+        Severity,
+        ValidateNested,
+        validate_nested,
+        validate_nested_reported,
+    };
+
+    // Test Types
+
+    struct Tag {
+        name: String,
+    }
+
+    impl ValidateNested for Tag {
+        fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+            mode.validate(&self.name, "name", &[&NotEmpty, &NoWhiteSpace])
+        }
+    }
+
+    struct Event {
+        name: String,
+        tags: Vec<Tag>,
+        parent: Option<Box<Event>>,
+        metadata: BTreeMap<String, Tag>,
+    }
+
+    impl ValidateNested for Event {
+        fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+            let mut collector = Collector::new(mode);
+
+            collector.collect(mode.validate(&self.name, "name", &[&NotEmpty]))?;
+            collector.collect(validate_nested(&self.tags, "tags", mode))?;
+            collector.collect(validate_nested(&self.parent, "parent", mode))?;
+            collector.collect(validate_nested(&self.metadata, "metadata", mode))?;
+            collector.finish()
+        }
+    }
+
+    struct Note {
+        text: String,
+    }
+
+    impl ValidateNested for Note {
+        fn validate_nested(&self, mode: Mode) -> Result<(), Error> {
+            self.validate_nested_reported(mode, &mut Report::new())
+        }
+
+        fn validate_nested_reported(&self, mode: Mode, report: &mut Report) -> Result<(), Error> {
+            mode.validate_reported(
+                &self.text,
+                "text",
+                &[&NotEmpty, &Advisory::warning(NoWhiteSpace)],
+                report,
+            )
+        }
+    }
+
+    // Test Helpers
+
+    fn tag(name: &str) -> Tag {
+        Tag {
+            name: name.to_owned(),
+        }
+    }
+
+    fn event(name: &str, tags: Vec<Tag>) -> Event {
+        Event {
+            name: name.to_owned(),
+            tags,
+            parent: None,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    fn failure(code: &'static str, path: &str) -> Failure {
+        Failure::new(code, code).with_path(path)
+    }
+
+    // Nested
+
+    #[test]
+    fn nested_valid() {
+        let value = event("created", vec![tag("a"), tag("b")]);
+
+        assert_ok!(value.validate_nested(Mode::FailFast));
+        assert_ok!(value.validate_nested(Mode::All));
+    }
+
+    #[test]
+    fn nested_vec_prefixes_index() {
+        let value = event("created", vec![tag("a"), tag("b c")]);
+
+        assert_eq!(
+            validate_nested(&value, "events[3]", Mode::FailFast),
+            Err(Error::Invalid(failure(
+                "whitespace",
+                "events[3].tags[1].name"
+            )))
+        );
+    }
+
+    #[test]
+    fn nested_option_and_box() {
+        let mut value = event("created", Vec::new());
+
+        value.parent = Some(Box::new(event("", Vec::new())));
+
+        assert_eq!(
+            value.validate_nested(Mode::FailFast),
+            Err(Error::Invalid(failure("empty", "parent.name")))
+        );
+    }
+
+    #[test]
+    fn nested_map_prefixes_key() {
+        let mut value = event("created", Vec::new());
+
+        value.metadata.insert(String::from("x y"), tag(""));
+
+        assert_eq!(
+            value.validate_nested(Mode::FailFast),
+            Err(Error::Invalid(failure("empty", r#"metadata["x y"].name"#)))
+        );
+    }
+
+    #[test]
+    fn nested_fail_fast_returns_first_failure() {
+        let value = event("", vec![tag(""), tag("b c")]);
+
+        assert_eq!(
+            value.validate_nested(Mode::FailFast),
+            Err(Error::Invalid(failure("empty", "name")))
+        );
+    }
+
+    #[test]
+    fn nested_all_returns_all_failures() {
+        let mut value = event("", vec![tag(""), tag("b c")]);
+
+        value.parent = Some(Box::new(event("", vec![tag("d e")])));
+
+        assert_eq!(
+            value.validate_nested(Mode::All),
+            Err(Error::InvalidAll(vec![
+                failure("empty", "name"),
+                failure("empty", "tags[0].name"),
+                failure("whitespace", "tags[1].name"),
+                failure("empty", "parent.name"),
+                failure("whitespace", "parent.tags[0].name"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn nested_hash_map() {
+        let value = HashMap::from([(1, tag("a b"))]);
+
+        assert_eq!(
+            validate_nested(&value, "tags", Mode::FailFast),
+            Err(Error::Invalid(failure("whitespace", r#"tags["1"].name"#)))
+        );
+    }
+
+    #[test]
+    fn nested_hash_map_sorted_by_key() {
+        let value = HashMap::from([
+            ("c", tag("")),
+            ("a", tag("a b")),
+            ("d", tag("d")),
+            ("b", tag("")),
+        ]);
+
+        assert_eq!(
+            validate_nested(&value, "tags", Mode::FailFast),
+            Err(Error::Invalid(failure("whitespace", r#"tags["a"].name"#)))
+        );
+        assert_eq!(
+            validate_nested(&value, "tags", Mode::All),
+            Err(Error::InvalidAll(vec![
+                failure("whitespace", r#"tags["a"].name"#),
+                failure("empty", r#"tags["b"].name"#),
+                failure("empty", r#"tags["c"].name"#),
+            ]))
+        );
+    }
+
+    #[test]
+    fn nested_reported_prefixes_advisory_failures() {
+        let value = BTreeMap::from([(
+            "b",
+            Some(Box::new(vec![
+                Note {
+                    text: String::from("a b"),
+                },
+                Note {
+                    text: String::new(),
+                },
+            ])),
+        )]);
+        let mut report = Report::new();
+
+        assert_eq!(
+            validate_nested_reported(&value, "notes", Mode::All, &mut report),
+            Err(Error::InvalidAll(vec![failure(
+                "empty",
+                r#"notes["b"][1].text"#
+            )]))
+        );
+        assert_eq!(report.into_failures(), vec![
+            failure("whitespace", r#"notes["b"][0].text"#).with_severity(Severity::Warning)
+        ]);
+        assert_eq!(
+            validate_nested(&value, "notes", Mode::FailFast),
+            Err(Error::Invalid(failure("empty", r#"notes["b"][1].text"#)))
+        );
+    }
+
+    #[test]
+    fn nested_slice_and_array() {
+        let value = [tag("a"), tag("")];
+
+        assert_eq!(
+            validate_nested(&value[..], "tags", Mode::FailFast),
+            Err(Error::Invalid(failure("empty", "tags[1].name")))
+        );
+        assert_eq!(
+            validate_nested(&value, "tags", Mode::FailFast),
+            Err(Error::Invalid(failure("empty", "tags[1].name")))
+        );
+    }
+
+    #[test]
+    fn nested_rc() {
+        let value = Rc::new(tag(""));
+
+        assert_eq!(
+            validate_nested(&value, "tag", Mode::FailFast),
+            Err(Error::Invalid(failure("empty", "tag.name")))
+        );
+    }
+}
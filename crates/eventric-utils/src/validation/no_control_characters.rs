@@ -1,20 +1,42 @@
-use crate::validation::Validator;
+use std::ffi::OsStr;
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    forward::{
+        forward_strings,
+        forward_wrappers,
+    },
+};
 
 // =================================================================================================
 // Control Characters
 // =================================================================================================
 
-/// Validates that a value does not contain white space characters.
+/// Validates that a value does not contain control characters.
 pub struct NoControlCharacters;
 
 impl<T> Validator<T> for NoControlCharacters
 where
-    T: ControlCharactersValidation,
+    T: ControlCharactersValidation + ?Sized,
 {
-    fn validate(&self, value: &T) -> Option<&str> {
+    fn validate(&self, value: &T) -> Option<Failure> {
         value
             .control_characters_validation()
-            .then_some("control characters")
+            .then(|| Failure::new("control_characters", "control characters"))
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for NoControlCharacters {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "pattern", r"^[^\u0000-\u001F\u007F-\u009F]*$");
     }
 }
 
@@ -22,32 +44,73 @@ where
 
 // Supporting Trait
 
-trait ControlCharactersValidation {
+/// Supports validation by [`NoControlCharacters`]. Implemented for the
+/// standard string and path types, and for references and smart pointers to
+/// them, and may be implemented for other types (such as newtypes) to make
+/// them validatable.
+pub trait ControlCharactersValidation {
+    /// Returns `true` if the value contains any control characters.
     fn control_characters_validation(&self) -> bool;
 }
 
-impl ControlCharactersValidation for String {
+impl ControlCharactersValidation for OsStr {
+    fn control_characters_validation(&self) -> bool {
+        self.to_string_lossy().contains(char::is_control)
+    }
+}
+
+impl ControlCharactersValidation for str {
     fn control_characters_validation(&self) -> bool {
         self.contains(char::is_control)
     }
 }
 
+forward_strings!(ControlCharactersValidation {
+    fn control_characters_validation(&self) -> bool;
+});
+
+forward_wrappers!(ControlCharactersValidation {
+    fn control_characters_validation(&self) -> bool;
+});
+
 // -------------------------------------------------------------------------------------------------
 
 // Tests
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        borrow::Cow,
+        ffi::OsString,
+        path::PathBuf,
+        rc::Rc,
+        sync::Arc,
+    };
+
     use assertables::{
         assert_none,
         assert_some_eq,
     };
 
     use crate::validation::{
+        Failure,
         Validator as _,
-        no_control_characters::NoControlCharacters,
+        no_control_characters::{
+            ControlCharactersValidation,
+            NoControlCharacters,
+        },
     };
 
+    // Test Types
+
+    struct Name(String);
+
+    impl ControlCharactersValidation for Name {
+        fn control_characters_validation(&self) -> bool {
+            self.0.control_characters_validation()
+        }
+    }
+
     // No Control Characters
 
     #[test]
@@ -63,7 +126,10 @@ mod tests {
         let validator = NoControlCharacters;
         let value = String::from("Hello\nWorld");
 
-        assert_some_eq!(Some("control characters"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -71,7 +137,10 @@ mod tests {
         let validator = NoControlCharacters;
         let value = String::from("Hello\tWorld");
 
-        assert_some_eq!(Some("control characters"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -79,7 +148,10 @@ mod tests {
         let validator = NoControlCharacters;
         let value = String::from("Hello\rWorld");
 
-        assert_some_eq!(Some("control characters"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -87,7 +159,10 @@ mod tests {
         let validator = NoControlCharacters;
         let value = String::from("Hello\0World");
 
-        assert_some_eq!(Some("control characters"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -95,7 +170,10 @@ mod tests {
         let validator = NoControlCharacters;
         let value = String::from("Hello\x07World");
 
-        assert_some_eq!(Some("control characters"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -103,7 +181,10 @@ mod tests {
         let validator = NoControlCharacters;
         let value = String::from("Hello\x1bWorld");
 
-        assert_some_eq!(Some("control characters"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -145,4 +226,158 @@ mod tests {
 
         assert_none!(validator.validate(&value));
     }
+
+    // Other Types
+
+    #[test]
+    fn no_control_characters_arc_str_valid() {
+        let validator = NoControlCharacters;
+        let value: Arc<str> = Arc::from("Hello World");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_control_characters_arc_str_invalid() {
+        let validator = NoControlCharacters;
+        let value: Arc<str> = Arc::from("Hello\nWorld");
+
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_control_characters_box_str_valid() {
+        let validator = NoControlCharacters;
+        let value: Box<str> = Box::from("Hello World");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_control_characters_box_str_invalid() {
+        let validator = NoControlCharacters;
+        let value: Box<str> = Box::from("Hello\nWorld");
+
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_control_characters_cow_str_valid() {
+        let validator = NoControlCharacters;
+        let value: Cow<'_, str> = Cow::Borrowed("Hello World");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_control_characters_cow_str_invalid() {
+        let validator = NoControlCharacters;
+        let value: Cow<'_, str> = Cow::Borrowed("Hello\nWorld");
+
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_control_characters_osstring_valid() {
+        let validator = NoControlCharacters;
+        let value = OsString::from("Hello World");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_control_characters_osstring_invalid() {
+        let validator = NoControlCharacters;
+        let value = OsString::from("Hello\nWorld");
+
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_control_characters_pathbuf_valid() {
+        let validator = NoControlCharacters;
+        let value = PathBuf::from("Hello World");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_control_characters_pathbuf_invalid() {
+        let validator = NoControlCharacters;
+        let value = PathBuf::from("Hello\nWorld");
+
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_control_characters_rc_str_valid() {
+        let validator = NoControlCharacters;
+        let value: Rc<str> = Rc::from("Hello World");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_control_characters_rc_str_invalid() {
+        let validator = NoControlCharacters;
+        let value: Rc<str> = Rc::from("Hello\nWorld");
+
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_control_characters_str_valid() {
+        let validator = NoControlCharacters;
+        let value: &str = "Hello World";
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_control_characters_str_invalid() {
+        let validator = NoControlCharacters;
+        let value: &str = "Hello\nWorld";
+
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_control_characters_newtype_valid() {
+        let validator = NoControlCharacters;
+        let value = Name(String::from("Hello World"));
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_control_characters_newtype_invalid() {
+        let validator = NoControlCharacters;
+        let value = Name(String::from("Hello\nWorld"));
+
+        assert_some_eq!(
+            Some(Failure::new("control_characters", "control characters")),
+            validator.validate(&value)
+        );
+    }
 }
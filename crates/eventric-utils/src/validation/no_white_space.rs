@@ -1,4 +1,19 @@
-use crate::validation::Validator;
+use std::ffi::OsStr;
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    forward::{
+        forward_strings,
+        forward_wrappers,
+    },
+};
 
 // =================================================================================================
 // White Space
@@ -9,10 +24,19 @@ pub struct NoWhiteSpace;
 
 impl<T> Validator<T> for NoWhiteSpace
 where
-    T: WhiteSpaceValidation,
+    T: WhiteSpaceValidation + ?Sized,
 {
-    fn validate(&self, value: &T) -> Option<&str> {
-        value.white_space_validation().then_some("whitespace")
+    fn validate(&self, value: &T) -> Option<Failure> {
+        value
+            .white_space_validation()
+            .then(|| Failure::new("whitespace", "whitespace"))
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for NoWhiteSpace {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "pattern", r"^\S*$");
     }
 }
 
@@ -21,12 +45,19 @@ pub struct NoPrecedingWhiteSpace;
 
 impl<T> Validator<T> for NoPrecedingWhiteSpace
 where
-    T: PrecedingWhiteSpaceValidation,
+    T: PrecedingWhiteSpaceValidation + ?Sized,
 {
-    fn validate(&self, value: &T) -> Option<&str> {
+    fn validate(&self, value: &T) -> Option<Failure> {
         value
             .preceding_white_space_validation()
-            .then_some("preceding whitespace")
+            .then(|| Failure::new("preceding_whitespace", "preceding whitespace"))
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for NoPrecedingWhiteSpace {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "pattern", r"^(?!\s)");
     }
 }
 
@@ -35,12 +66,19 @@ pub struct NoTrailingWhiteSpace;
 
 impl<T> Validator<T> for NoTrailingWhiteSpace
 where
-    T: TrailingWhiteSpaceValidation,
+    T: TrailingWhiteSpaceValidation + ?Sized,
 {
-    fn validate(&self, value: &T) -> Option<&str> {
+    fn validate(&self, value: &T) -> Option<Failure> {
         value
             .trailing_white_space_validation()
-            .then_some("preceding whitespace")
+            .then(|| Failure::new("trailing_whitespace", "trailing whitespace"))
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for NoTrailingWhiteSpace {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "pattern", r"(?<!\s)$");
     }
 }
 
@@ -48,56 +86,132 @@ where
 
 // Supporting Traits
 
-trait WhiteSpaceValidation {
+/// Supports validation by [`NoWhiteSpace`]. Implemented for the standard string
+/// and path types, and for references and smart pointers to them, and may be
+/// implemented for other types (such as newtypes) to make them validatable.
+pub trait WhiteSpaceValidation {
+    /// Returns `true` if the value contains any white space characters.
     fn white_space_validation(&self) -> bool;
 }
 
-impl WhiteSpaceValidation for String {
+impl WhiteSpaceValidation for OsStr {
+    fn white_space_validation(&self) -> bool {
+        self.to_string_lossy().contains(char::is_whitespace)
+    }
+}
+
+impl WhiteSpaceValidation for str {
     fn white_space_validation(&self) -> bool {
         self.contains(char::is_whitespace)
     }
 }
 
-trait PrecedingWhiteSpaceValidation {
+forward_strings!(WhiteSpaceValidation {
+    fn white_space_validation(&self) -> bool;
+});
+
+forward_wrappers!(WhiteSpaceValidation {
+    fn white_space_validation(&self) -> bool;
+});
+
+/// Supports validation by [`NoPrecedingWhiteSpace`]. Implemented for the
+/// standard string and path types, and for references and smart pointers to
+/// them, and may be implemented for other types (such as newtypes) to make them
+/// validatable.
+pub trait PrecedingWhiteSpaceValidation {
+    /// Returns `true` if the value starts with a white space character.
     fn preceding_white_space_validation(&self) -> bool;
 }
 
-impl PrecedingWhiteSpaceValidation for String {
+impl PrecedingWhiteSpaceValidation for OsStr {
+    fn preceding_white_space_validation(&self) -> bool {
+        self.to_string_lossy().starts_with(char::is_whitespace)
+    }
+}
+
+impl PrecedingWhiteSpaceValidation for str {
     fn preceding_white_space_validation(&self) -> bool {
         self.starts_with(char::is_whitespace)
     }
 }
 
-trait TrailingWhiteSpaceValidation {
+forward_strings!(PrecedingWhiteSpaceValidation {
+    fn preceding_white_space_validation(&self) -> bool;
+});
+
+forward_wrappers!(PrecedingWhiteSpaceValidation {
+    fn preceding_white_space_validation(&self) -> bool;
+});
+
+/// Supports validation by [`NoTrailingWhiteSpace`]. Implemented for the
+/// standard string and path types, and for references and smart pointers to
+/// them, and may be implemented for other types (such as newtypes) to make them
+/// validatable.
+pub trait TrailingWhiteSpaceValidation {
+    /// Returns `true` if the value ends with a white space character.
     fn trailing_white_space_validation(&self) -> bool;
 }
 
-impl TrailingWhiteSpaceValidation for String {
+impl TrailingWhiteSpaceValidation for OsStr {
+    fn trailing_white_space_validation(&self) -> bool {
+        self.to_string_lossy().ends_with(char::is_whitespace)
+    }
+}
+
+impl TrailingWhiteSpaceValidation for str {
     fn trailing_white_space_validation(&self) -> bool {
         self.ends_with(char::is_whitespace)
     }
 }
 
+forward_strings!(TrailingWhiteSpaceValidation {
+    fn trailing_white_space_validation(&self) -> bool;
+});
+
+forward_wrappers!(TrailingWhiteSpaceValidation {
+    fn trailing_white_space_validation(&self) -> bool;
+});
+
 // -------------------------------------------------------------------------------------------------
 
 // Tests
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        borrow::Cow,
+        ffi::OsString,
+        path::PathBuf,
+        rc::Rc,
+        sync::Arc,
+    };
+
     use assertables::{
         assert_none,
         assert_some_eq,
     };
 
     use crate::validation::{
+        Failure,
         Validator as _,
         no_white_space::{
             NoPrecedingWhiteSpace,
             NoTrailingWhiteSpace,
             NoWhiteSpace,
+            WhiteSpaceValidation,
         },
     };
 
+    // Test Types
+
+    struct Name(String);
+
+    impl WhiteSpaceValidation for Name {
+        fn white_space_validation(&self) -> bool {
+            self.0.white_space_validation()
+        }
+    }
+
     // No White Space
 
     #[test]
@@ -113,7 +227,10 @@ mod tests {
         let validator = NoWhiteSpace;
         let value = String::from("Hello World");
 
-        assert_some_eq!(Some("whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -121,7 +238,10 @@ mod tests {
         let validator = NoWhiteSpace;
         let value = String::from("Hello\tWorld");
 
-        assert_some_eq!(Some("whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -129,7 +249,10 @@ mod tests {
         let validator = NoWhiteSpace;
         let value = String::from("Hello\nWorld");
 
-        assert_some_eq!(Some("whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -137,7 +260,10 @@ mod tests {
         let validator = NoWhiteSpace;
         let value = String::from(" Hello");
 
-        assert_some_eq!(Some("whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -145,7 +271,10 @@ mod tests {
         let validator = NoWhiteSpace;
         let value = String::from("Hello ");
 
-        assert_some_eq!(Some("whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -179,7 +308,10 @@ mod tests {
         let validator = NoPrecedingWhiteSpace;
         let value = String::from(" Hello");
 
-        assert_some_eq!(Some("preceding whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("preceding_whitespace", "preceding whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -187,7 +319,10 @@ mod tests {
         let validator = NoPrecedingWhiteSpace;
         let value = String::from("\tHello");
 
-        assert_some_eq!(Some("preceding whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("preceding_whitespace", "preceding whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -195,7 +330,10 @@ mod tests {
         let validator = NoPrecedingWhiteSpace;
         let value = String::from("\nHello");
 
-        assert_some_eq!(Some("preceding whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("preceding_whitespace", "preceding whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -245,7 +383,10 @@ mod tests {
         let validator = NoTrailingWhiteSpace;
         let value = String::from("Hello ");
 
-        assert_some_eq!(Some("preceding whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("trailing_whitespace", "trailing whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -253,7 +394,10 @@ mod tests {
         let validator = NoTrailingWhiteSpace;
         let value = String::from("Hello\t");
 
-        assert_some_eq!(Some("preceding whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("trailing_whitespace", "trailing whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -261,7 +405,10 @@ mod tests {
         let validator = NoTrailingWhiteSpace;
         let value = String::from("Hello\n");
 
-        assert_some_eq!(Some("preceding whitespace"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("trailing_whitespace", "trailing whitespace")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -295,4 +442,238 @@ mod tests {
 
         assert_none!(validator.validate(&value));
     }
+
+    // No White Space - Other Types
+
+    #[test]
+    fn no_white_space_arc_str_valid() {
+        let validator = NoWhiteSpace;
+        let value: Arc<str> = Arc::from("HelloWorld");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_white_space_arc_str_invalid() {
+        let validator = NoWhiteSpace;
+        let value: Arc<str> = Arc::from("Hello World");
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_white_space_box_str_valid() {
+        let validator = NoWhiteSpace;
+        let value: Box<str> = Box::from("HelloWorld");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_white_space_box_str_invalid() {
+        let validator = NoWhiteSpace;
+        let value: Box<str> = Box::from("Hello World");
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_white_space_cow_str_valid() {
+        let validator = NoWhiteSpace;
+        let value: Cow<'_, str> = Cow::Borrowed("HelloWorld");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_white_space_cow_str_invalid() {
+        let validator = NoWhiteSpace;
+        let value: Cow<'_, str> = Cow::Borrowed("Hello World");
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_white_space_osstring_valid() {
+        let validator = NoWhiteSpace;
+        let value = OsString::from("HelloWorld");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_white_space_osstring_invalid() {
+        let validator = NoWhiteSpace;
+        let value = OsString::from("Hello World");
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_white_space_pathbuf_valid() {
+        let validator = NoWhiteSpace;
+        let value = PathBuf::from("HelloWorld");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_white_space_pathbuf_invalid() {
+        let validator = NoWhiteSpace;
+        let value = PathBuf::from("Hello World");
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_white_space_rc_str_valid() {
+        let validator = NoWhiteSpace;
+        let value: Rc<str> = Rc::from("HelloWorld");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_white_space_rc_str_invalid() {
+        let validator = NoWhiteSpace;
+        let value: Rc<str> = Rc::from("Hello World");
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_white_space_str_valid() {
+        let validator = NoWhiteSpace;
+        let value: &str = "HelloWorld";
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_white_space_str_invalid() {
+        let validator = NoWhiteSpace;
+        let value: &str = "Hello World";
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_white_space_newtype_valid() {
+        let validator = NoWhiteSpace;
+        let value = Name(String::from("HelloWorld"));
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_white_space_newtype_invalid() {
+        let validator = NoWhiteSpace;
+        let value = Name(String::from("Hello World"));
+
+        assert_some_eq!(
+            Some(Failure::new("whitespace", "whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    // No Preceding White Space - Other Types
+
+    #[test]
+    fn no_preceding_white_space_box_str_valid() {
+        let validator = NoPrecedingWhiteSpace;
+        let value: Box<str> = Box::from("Hello ");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_preceding_white_space_box_str_invalid() {
+        let validator = NoPrecedingWhiteSpace;
+        let value: Box<str> = Box::from(" Hello");
+
+        assert_some_eq!(
+            Some(Failure::new("preceding_whitespace", "preceding whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_preceding_white_space_str_valid() {
+        let validator = NoPrecedingWhiteSpace;
+        let value: &str = "Hello ";
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_preceding_white_space_str_invalid() {
+        let validator = NoPrecedingWhiteSpace;
+        let value: &str = " Hello";
+
+        assert_some_eq!(
+            Some(Failure::new("preceding_whitespace", "preceding whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    // No Trailing White Space - Other Types
+
+    #[test]
+    fn no_trailing_white_space_box_str_valid() {
+        let validator = NoTrailingWhiteSpace;
+        let value: Box<str> = Box::from(" Hello");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_trailing_white_space_box_str_invalid() {
+        let validator = NoTrailingWhiteSpace;
+        let value: Box<str> = Box::from("Hello ");
+
+        assert_some_eq!(
+            Some(Failure::new("trailing_whitespace", "trailing whitespace")),
+            validator.validate(&value)
+        );
+    }
+
+    #[test]
+    fn no_trailing_white_space_str_valid() {
+        let validator = NoTrailingWhiteSpace;
+        let value: &str = " Hello";
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn no_trailing_white_space_str_invalid() {
+        let validator = NoTrailingWhiteSpace;
+        let value: &str = "Hello ";
+
+        assert_some_eq!(
+            Some(Failure::new("trailing_whitespace", "trailing whitespace")),
+            validator.validate(&value)
+        );
+    }
 }
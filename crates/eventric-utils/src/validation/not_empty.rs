@@ -1,11 +1,30 @@
-use std::collections::{
-    BTreeMap,
-    BTreeSet,
-    HashMap,
-    HashSet,
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        BinaryHeap,
+        HashMap,
+        HashSet,
+        LinkedList,
+        VecDeque,
+    },
+    ffi::OsStr,
 };
 
-use crate::validation::Validator;
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    forward::{
+        forward_strings,
+        forward_wrappers,
+    },
+};
 
 // =================================================================================================
 // Not Empty
@@ -16,10 +35,21 @@ pub struct NotEmpty;
 
 impl<T> Validator<T> for NotEmpty
 where
-    T: IsEmptyValidation,
+    T: IsEmptyValidation + ?Sized,
 {
-    fn validate(&self, value: &T) -> Option<&str> {
-        value.is_empty_validation().then_some("empty")
+    fn validate(&self, value: &T) -> Option<Failure> {
+        value
+            .is_empty_validation()
+            .then(|| Failure::new("empty", "empty"))
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for NotEmpty {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "minLength", 1);
+        keyword(schema, "minItems", 1);
+        keyword(schema, "minProperties", 1);
     }
 }
 
@@ -27,8 +57,12 @@ where
 
 // Supporting Trait
 
-trait IsEmptyValidation {
-    /// .
+/// Supports validation by [`NotEmpty`]. Implemented for the standard string,
+/// path, and collection types, and for references and smart pointers to them,
+/// and may be implemented for other types (such as newtypes or other
+/// collection types) to make them validatable.
+pub trait IsEmptyValidation {
+    /// Returns `true` if the value is empty.
     fn is_empty_validation(&self) -> bool;
 }
 
@@ -38,6 +72,18 @@ impl<T, const N: usize> IsEmptyValidation for [T; N] {
     }
 }
 
+impl<T> IsEmptyValidation for [T] {
+    fn is_empty_validation(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> IsEmptyValidation for BinaryHeap<T> {
+    fn is_empty_validation(&self) -> bool {
+        self.is_empty()
+    }
+}
+
 impl<T, U> IsEmptyValidation for BTreeMap<T, U> {
     fn is_empty_validation(&self) -> bool {
         self.is_empty()
@@ -62,7 +108,19 @@ impl<T, S> IsEmptyValidation for HashSet<T, S> {
     }
 }
 
-impl IsEmptyValidation for String {
+impl<T> IsEmptyValidation for LinkedList<T> {
+    fn is_empty_validation(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl IsEmptyValidation for OsStr {
+    fn is_empty_validation(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl IsEmptyValidation for str {
     fn is_empty_validation(&self) -> bool {
         self.is_empty()
     }
@@ -74,17 +132,41 @@ impl<T> IsEmptyValidation for Vec<T> {
     }
 }
 
+impl<T> IsEmptyValidation for VecDeque<T> {
+    fn is_empty_validation(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+forward_strings!(IsEmptyValidation {
+    fn is_empty_validation(&self) -> bool;
+});
+
+forward_wrappers!(IsEmptyValidation {
+    fn is_empty_validation(&self) -> bool;
+});
+
 // -------------------------------------------------------------------------------------------------
 
 // Tests
 
 #[cfg(test)]
 mod tests {
-    use std::collections::{
-        BTreeMap,
-        BTreeSet,
-        HashMap,
-        HashSet,
+    use std::{
+        borrow::Cow,
+        collections::{
+            BTreeMap,
+            BTreeSet,
+            BinaryHeap,
+            HashMap,
+            HashSet,
+            LinkedList,
+            VecDeque,
+        },
+        ffi::OsString,
+        path::PathBuf,
+        rc::Rc,
+        sync::Arc,
     };
 
     use assertables::{
@@ -93,10 +175,24 @@ mod tests {
     };
 
     use crate::validation::{
+        Failure,
         Validator as _,
-        not_empty::NotEmpty,
+        not_empty::{
+            IsEmptyValidation,
+            NotEmpty,
+        },
     };
 
+    // Test Types
+
+    struct Tags(Vec<String>);
+
+    impl IsEmptyValidation for Tags {
+        fn is_empty_validation(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
     // Array
 
     #[test]
@@ -112,7 +208,10 @@ mod tests {
         let validator = NotEmpty;
         let value: [i32; 0] = [];
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -138,7 +237,10 @@ mod tests {
         let validator = NotEmpty;
         let value: BTreeMap<i32, &str> = BTreeMap::new();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -156,7 +258,10 @@ mod tests {
 
         value.clear();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     // BTreeSet
@@ -174,7 +279,10 @@ mod tests {
         let validator = NotEmpty;
         let value: BTreeSet<i32> = BTreeSet::new();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -192,7 +300,10 @@ mod tests {
 
         value.clear();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     // HashMap
@@ -210,7 +321,10 @@ mod tests {
         let validator = NotEmpty;
         let value: HashMap<i32, &str> = HashMap::new();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -228,7 +342,10 @@ mod tests {
 
         value.clear();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     // HashSet
@@ -246,7 +363,10 @@ mod tests {
         let validator = NotEmpty;
         let value: HashSet<i32> = HashSet::new();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -264,7 +384,10 @@ mod tests {
 
         value.clear();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     // String
@@ -282,7 +405,10 @@ mod tests {
         let validator = NotEmpty;
         let value = String::new();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -300,7 +426,10 @@ mod tests {
 
         value.clear();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     // Vec
@@ -318,7 +447,10 @@ mod tests {
         let validator = NotEmpty;
         let value: Vec<i32> = Vec::new();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 
     #[test]
@@ -336,6 +468,261 @@ mod tests {
 
         value.clear();
 
-        assert_some_eq!(Some("empty"), validator.validate(&value));
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // Arc<str>
+
+    #[test]
+    fn not_empty_arc_str_valid() {
+        let validator = NotEmpty;
+        let value: Arc<str> = Arc::from("Hello");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_arc_str_invalid() {
+        let validator = NotEmpty;
+        let value: Arc<str> = Arc::from("");
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // BinaryHeap
+
+    #[test]
+    fn not_empty_binaryheap_valid() {
+        let validator = NotEmpty;
+        let value: BinaryHeap<i32> = BinaryHeap::from([1, 2, 3]);
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_binaryheap_invalid() {
+        let validator = NotEmpty;
+        let value: BinaryHeap<i32> = BinaryHeap::new();
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // Box<str>
+
+    #[test]
+    fn not_empty_box_str_valid() {
+        let validator = NotEmpty;
+        let value: Box<str> = Box::from("Hello");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_box_str_invalid() {
+        let validator = NotEmpty;
+        let value: Box<str> = Box::from("");
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // Cow<str>
+
+    #[test]
+    fn not_empty_cow_str_valid() {
+        let validator = NotEmpty;
+        let value: Cow<'_, str> = Cow::Borrowed("Hello");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_cow_str_invalid() {
+        let validator = NotEmpty;
+        let value: Cow<'_, str> = Cow::Owned(String::new());
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // LinkedList
+
+    #[test]
+    fn not_empty_linkedlist_valid() {
+        let validator = NotEmpty;
+        let value: LinkedList<i32> = LinkedList::from([1, 2, 3]);
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_linkedlist_invalid() {
+        let validator = NotEmpty;
+        let value: LinkedList<i32> = LinkedList::new();
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // OsString
+
+    #[test]
+    fn not_empty_osstring_valid() {
+        let validator = NotEmpty;
+        let value = OsString::from("Hello");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_osstring_invalid() {
+        let validator = NotEmpty;
+        let value = OsString::new();
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // PathBuf
+
+    #[test]
+    fn not_empty_pathbuf_valid() {
+        let validator = NotEmpty;
+        let value = PathBuf::from("/tmp");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_pathbuf_invalid() {
+        let validator = NotEmpty;
+        let value = PathBuf::new();
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // Rc<str>
+
+    #[test]
+    fn not_empty_rc_str_valid() {
+        let validator = NotEmpty;
+        let value: Rc<str> = Rc::from("Hello");
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_rc_str_invalid() {
+        let validator = NotEmpty;
+        let value: Rc<str> = Rc::from("");
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // Slice
+
+    #[test]
+    fn not_empty_slice_valid() {
+        let validator = NotEmpty;
+        let value: &[i32] = &[1, 2, 3][..];
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_slice_invalid() {
+        let validator = NotEmpty;
+        let value: &[i32] = &[][..];
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // Str
+
+    #[test]
+    fn not_empty_str_valid() {
+        let validator = NotEmpty;
+        let value: &str = "Hello";
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_str_invalid() {
+        let validator = NotEmpty;
+        let value: &str = "";
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // VecDeque
+
+    #[test]
+    fn not_empty_vecdeque_valid() {
+        let validator = NotEmpty;
+        let value: VecDeque<i32> = VecDeque::from([1, 2, 3]);
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_vecdeque_invalid() {
+        let validator = NotEmpty;
+        let value: VecDeque<i32> = VecDeque::new();
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
+    }
+
+    // Newtype
+
+    #[test]
+    fn not_empty_newtype_valid() {
+        let validator = NotEmpty;
+        let value = Tags(vec![String::from("tag")]);
+
+        assert_none!(validator.validate(&value));
+    }
+
+    #[test]
+    fn not_empty_newtype_invalid() {
+        let validator = NotEmpty;
+        let value = Tags(Vec::new());
+
+        assert_some_eq!(
+            Some(Failure::new("empty", "empty")),
+            validator.validate(&value)
+        );
     }
 }
@@ -0,0 +1,626 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    num::{
+        NonZeroI8,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize,
+        NonZeroU8,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize,
+    },
+};
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    Value,
+};
+
+// =================================================================================================
+// Numeric
+// =================================================================================================
+
+/// Validates that a number is at least the given minimum (inclusive). Values
+/// which can not be compared to the minimum (NaN) are invalid.
+#[derive(Clone, Copy, Debug)]
+pub struct Min<T>(T);
+
+impl<T> Min<T> {
+    /// Creates a new [`Min`] validator with the given minimum.
+    pub fn new(min: T) -> Self {
+        Self(min)
+    }
+}
+
+impl<T> Validator<T> for Min<T>
+where
+    T: NumericValidation,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        matches!(value.partial_cmp(&self.0), None | Some(Ordering::Less)).then(|| {
+            Failure::new("min", format!("must be at least {}, got {value}", self.0))
+                .with_param("min", self.0)
+                .with_param("actual", *value)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<T> Describe for Min<T>
+where
+    T: NumericValidation,
+{
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "minimum", Into::<Value>::into(self.0));
+    }
+}
+
+/// Validates that a number is at most the given maximum (inclusive). Values
+/// which can not be compared to the maximum (NaN) are invalid.
+#[derive(Clone, Copy, Debug)]
+pub struct Max<T>(T);
+
+impl<T> Max<T> {
+    /// Creates a new [`Max`] validator with the given maximum.
+    pub fn new(max: T) -> Self {
+        Self(max)
+    }
+}
+
+impl<T> Validator<T> for Max<T>
+where
+    T: NumericValidation,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        matches!(value.partial_cmp(&self.0), None | Some(Ordering::Greater)).then(|| {
+            Failure::new("max", format!("must be at most {}, got {value}", self.0))
+                .with_param("max", self.0)
+                .with_param("actual", *value)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<T> Describe for Max<T>
+where
+    T: NumericValidation,
+{
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "maximum", Into::<Value>::into(self.0));
+    }
+}
+
+/// Validates that a number is between the given minimum and maximum
+/// (inclusive). Values which can not be compared to the bounds (NaN) are
+/// invalid.
+#[derive(Clone, Copy, Debug)]
+pub struct Range<T> {
+    max: T,
+    min: T,
+}
+
+impl<T> Range<T> {
+    /// Creates a new [`Range`] validator with the given minimum and maximum.
+    pub fn new(min: T, max: T) -> Self {
+        Self { max, min }
+    }
+}
+
+impl<T> Validator<T> for Range<T>
+where
+    T: NumericValidation,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let below = matches!(value.partial_cmp(&self.min), None | Some(Ordering::Less));
+        let above = matches!(value.partial_cmp(&self.max), None | Some(Ordering::Greater));
+
+        (below || above).then(|| {
+            Failure::new(
+                "range",
+                format!("must be between {} and {}, got {value}", self.min, self.max),
+            )
+            .with_param("min", self.min)
+            .with_param("max", self.max)
+            .with_param("actual", *value)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<T> Describe for Range<T>
+where
+    T: NumericValidation,
+{
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "minimum", Into::<Value>::into(self.min));
+        keyword(schema, "maximum", Into::<Value>::into(self.max));
+    }
+}
+
+/// Validates that a number is greater than zero.
+pub struct Positive;
+
+impl<T> Validator<T> for Positive
+where
+    T: NumericValidation,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (value.sign_validation() != Some(Ordering::Greater)).then(|| {
+            Failure::new("not_positive", format!("must be positive, got {value}"))
+                .with_param("actual", *value)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for Positive {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "exclusiveMinimum", 0);
+    }
+}
+
+/// Validates that a number is greater than or equal to zero.
+pub struct NonNegative;
+
+impl<T> Validator<T> for NonNegative
+where
+    T: NumericValidation,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        matches!(value.sign_validation(), None | Some(Ordering::Less)).then(|| {
+            Failure::new("negative", format!("must not be negative, got {value}"))
+                .with_param("actual", *value)
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for NonNegative {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "minimum", 0);
+    }
+}
+
+/// Validates that a number is not zero.
+pub struct NonZero;
+
+impl<T> Validator<T> for NonZero
+where
+    T: NumericValidation,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (value.sign_validation() == Some(Ordering::Equal)).then(|| Failure::new("zero", "zero"))
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for NonZero {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "not", serde_json::json!({ "const": 0 }));
+    }
+}
+
+/// Validates that a number is a multiple of the given divisor. For floating
+/// point numbers, the remainder must be exactly zero (no tolerance is
+/// applied), and zero is the only multiple of zero.
+#[derive(Clone, Copy, Debug)]
+pub struct MultipleOf<T>(T);
+
+impl<T> MultipleOf<T> {
+    /// Creates a new [`MultipleOf`] validator with the given divisor.
+    pub fn new(divisor: T) -> Self {
+        Self(divisor)
+    }
+}
+
+impl<T> Validator<T> for MultipleOf<T>
+where
+    T: NumericValidation,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (!value.multiple_of_validation(&self.0)).then(|| {
+            Failure::new(
+                "not_multiple_of",
+                format!("must be a multiple of {}, got {value}", self.0),
+            )
+            .with_param("divisor", self.0)
+            .with_param("actual", *value)
+        })
+    }
+}
+
+/// JSON Schema requires a positive `multipleOf`, so non-positive divisors are
+/// not described, and add no keywords.
+#[cfg(feature = "schema")]
+impl<T> Describe for MultipleOf<T>
+where
+    T: NumericValidation,
+{
+    fn describe(&self, schema: &mut Schema) {
+        if self.0.sign_validation() == Some(Ordering::Greater) {
+            keyword(schema, "multipleOf", Into::<Value>::into(self.0));
+        }
+    }
+}
+
+/// Validates that a number is finite (neither NaN nor infinite). Integer
+/// values are always finite.
+pub struct Finite;
+
+impl<T> Validator<T> for Finite
+where
+    T: NumericValidation,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (!value.finite_validation()).then(|| {
+            Failure::new("not_finite", format!("must be finite, got {value}"))
+                .with_param("actual", *value)
+        })
+    }
+}
+
+/// JSON numbers are always finite, so [`Finite`] adds no keywords.
+#[cfg(feature = "schema")]
+impl Describe for Finite {
+    fn describe(&self, _: &mut Schema) {}
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Trait
+
+/// Supports validation by the numeric validators ([`Min`], [`Max`], [`Range`],
+/// [`Positive`], [`NonNegative`], [`NonZero`], [`MultipleOf`], and
+/// [`Finite`]). Implemented for all primitive integer and floating point
+/// types, and for the `NonZero*` integer types.
+pub trait NumericValidation: Copy + Display + Into<Value> + PartialOrd {
+    /// Returns `true` if the value is finite.
+    fn finite_validation(&self) -> bool;
+
+    /// Returns `true` if the value is a multiple of the given divisor.
+    fn multiple_of_validation(&self, divisor: &Self) -> bool;
+
+    /// Returns the ordering of the value relative to zero, or `None` where the
+    /// value can not be compared to zero (NaN).
+    fn sign_validation(&self) -> Option<Ordering>;
+}
+
+macro_rules! numeric_validation_float {
+    ($($ty:ty),*) => {
+        $(
+            impl NumericValidation for $ty {
+                fn finite_validation(&self) -> bool {
+                    self.is_finite()
+                }
+
+                fn multiple_of_validation(&self, divisor: &Self) -> bool {
+                    if *divisor == 0.0 {
+                        *self == 0.0
+                    } else {
+                        (self % divisor) == 0.0
+                    }
+                }
+
+                fn sign_validation(&self) -> Option<Ordering> {
+                    self.partial_cmp(&0.0)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! numeric_validation_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl NumericValidation for $ty {
+                fn finite_validation(&self) -> bool {
+                    true
+                }
+
+                fn multiple_of_validation(&self, divisor: &Self) -> bool {
+                    match self.checked_rem(*divisor) {
+                        Some(remainder) => remainder == 0,
+                        None => *divisor != 0 || *self == 0,
+                    }
+                }
+
+                fn sign_validation(&self) -> Option<Ordering> {
+                    Some(self.cmp(&0))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! numeric_validation_non_zero {
+    ($($ty:ty),*) => {
+        $(
+            impl NumericValidation for $ty {
+                fn finite_validation(&self) -> bool {
+                    true
+                }
+
+                fn multiple_of_validation(&self, divisor: &Self) -> bool {
+                    self.get().multiple_of_validation(&divisor.get())
+                }
+
+                fn sign_validation(&self) -> Option<Ordering> {
+                    self.get().sign_validation()
+                }
+            }
+        )*
+    };
+}
+
+numeric_validation_float!(f32, f64);
+
+numeric_validation_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+numeric_validation_non_zero!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::num::{
+        NonZeroI32,
+        NonZeroU64,
+    };
+
+    use assertables::{
+        assert_none,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Failure,
+        Validator as _,
+        numeric::{
+            Finite,
+            Max,
+            Min,
+            MultipleOf,
+            NonNegative,
+            NonZero,
+            Positive,
+            Range,
+        },
+    };
+
+    // Min
+
+    #[test]
+    fn min_valid() {
+        let validator = Min::new(10);
+
+        assert_none!(validator.validate(&10));
+        assert_none!(validator.validate(&11));
+    }
+
+    #[test]
+    fn min_invalid() {
+        let validator = Min::new(10_u8);
+
+        assert_some_eq!(
+            Some(
+                Failure::new("min", "must be at least 10, got 9")
+                    .with_param("min", 10_u8)
+                    .with_param("actual", 9_u8)
+            ),
+            validator.validate(&9)
+        );
+    }
+
+    #[test]
+    fn min_float_nan_invalid() {
+        let validator = Min::new(0.0);
+
+        assert_some_eq!(
+            Some("must be at least 0, got NaN"),
+            validator.validate(&f64::NAN).as_ref().map(Failure::message)
+        );
+    }
+
+    #[test]
+    fn min_non_zero() {
+        let validator = Min::new(NonZeroU64::new(5).unwrap());
+
+        assert_none!(validator.validate(&NonZeroU64::new(5).unwrap()));
+        assert_some_eq!(
+            Some(
+                Failure::new("min", "must be at least 5, got 4")
+                    .with_param("min", 5_u64)
+                    .with_param("actual", 4_u64)
+            ),
+            validator.validate(&NonZeroU64::new(4).unwrap())
+        );
+    }
+
+    // Max
+
+    #[test]
+    fn max_valid() {
+        let validator = Max::new(1.5_f32);
+
+        assert_none!(validator.validate(&1.5));
+        assert_none!(validator.validate(&f32::NEG_INFINITY));
+    }
+
+    #[test]
+    fn max_invalid() {
+        let validator = Max::new(100_i64);
+
+        assert_some_eq!(
+            Some(
+                Failure::new("max", "must be at most 100, got 101")
+                    .with_param("max", 100_i64)
+                    .with_param("actual", 101_i64)
+            ),
+            validator.validate(&101)
+        );
+    }
+
+    // Range
+
+    #[test]
+    fn range_valid() {
+        let validator = Range::new(-5_isize, 5);
+
+        assert_none!(validator.validate(&-5));
+        assert_none!(validator.validate(&0));
+        assert_none!(validator.validate(&5));
+    }
+
+    #[test]
+    fn range_invalid() {
+        let validator = Range::new(1_u16, 3);
+        let failure = |actual: u16| {
+            Failure::new("range", format!("must be between 1 and 3, got {actual}"))
+                .with_param("min", 1_u16)
+                .with_param("max", 3_u16)
+                .with_param("actual", actual)
+        };
+
+        assert_some_eq!(Some(failure(0)), validator.validate(&0));
+        assert_some_eq!(Some(failure(4)), validator.validate(&4));
+    }
+
+    // Positive
+
+    #[test]
+    fn positive_valid() {
+        assert_none!(Positive.validate(&1_i8));
+        assert_none!(Positive.validate(&0.1_f64));
+        assert_none!(Positive.validate(&NonZeroI32::new(1).unwrap()));
+    }
+
+    #[test]
+    fn positive_invalid() {
+        assert_some_eq!(
+            Some(
+                Failure::new("not_positive", "must be positive, got 0").with_param("actual", 0_u32)
+            ),
+            Positive.validate(&0_u32)
+        );
+        assert_some_eq!(
+            Some(Failure::new("not_positive", "must be positive, got -1").with_param("actual", -1)),
+            Positive.validate(&NonZeroI32::new(-1).unwrap())
+        );
+    }
+
+    // Non Negative
+
+    #[test]
+    fn non_negative_valid() {
+        assert_none!(NonNegative.validate(&0_i32));
+        assert_none!(NonNegative.validate(&-0.0_f64));
+        assert_none!(NonNegative.validate(&u128::MAX));
+    }
+
+    #[test]
+    fn non_negative_invalid() {
+        assert_some_eq!(
+            Some(
+                Failure::new("negative", "must not be negative, got -1")
+                    .with_param("actual", -1_i16)
+            ),
+            NonNegative.validate(&-1_i16)
+        );
+        assert!(NonNegative.validate(&f32::NAN).is_some());
+    }
+
+    // Non Zero
+
+    #[test]
+    fn non_zero_valid() {
+        assert_none!(NonZero.validate(&1_usize));
+        assert_none!(NonZero.validate(&-1.0_f32));
+    }
+
+    #[test]
+    fn non_zero_invalid() {
+        assert_some_eq!(
+            Some(Failure::new("zero", "zero")),
+            NonZero.validate(&0_i128)
+        );
+        assert_some_eq!(
+            Some(Failure::new("zero", "zero")),
+            NonZero.validate(&-0.0_f64)
+        );
+    }
+
+    // Multiple Of
+
+    #[test]
+    fn multiple_of_valid() {
+        assert_none!(MultipleOf::new(3).validate(&9));
+        assert_none!(MultipleOf::new(3).validate(&-9));
+        assert_none!(MultipleOf::new(0.25).validate(&1.5));
+        assert_none!(MultipleOf::new(-1_i8).validate(&i8::MIN));
+        assert_none!(MultipleOf::new(0).validate(&0));
+    }
+
+    #[test]
+    fn multiple_of_invalid() {
+        assert_some_eq!(
+            Some(
+                Failure::new("not_multiple_of", "must be a multiple of 3, got 10")
+                    .with_param("divisor", 3_u32)
+                    .with_param("actual", 10_u32)
+            ),
+            MultipleOf::new(3_u32).validate(&10)
+        );
+        assert!(MultipleOf::new(0).validate(&1).is_some());
+        assert!(MultipleOf::new(0.5).validate(&0.75).is_some());
+    }
+
+    // Finite
+
+    #[test]
+    fn finite_valid() {
+        assert_none!(Finite.validate(&1.0_f64));
+        assert_none!(Finite.validate(&i32::MAX));
+    }
+
+    #[test]
+    fn finite_invalid() {
+        assert_some_eq!(
+            Some(
+                Failure::new("not_finite", "must be finite, got inf")
+                    .with_param("actual", f64::INFINITY)
+            ),
+            Finite.validate(&f64::INFINITY)
+        );
+        assert!(Finite.validate(&f32::NAN).is_some());
+    }
+}
@@ -0,0 +1,352 @@
+use std::{
+    borrow::Cow,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+        Write as _,
+    },
+};
+
+// =================================================================================================
+// Path
+// =================================================================================================
+
+/// A path to the value which failed validation, relative to the value being
+/// validated, such as `events[3].tags[0]`, made up of a sequence of
+/// [`Segment`]s.
+///
+/// Paths can be built up segment by segment, or parsed from a string in the
+/// same form as the path is displayed.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Path(Vec<Segment>);
+
+impl Path {
+    /// Creates a new, empty [`Path`], referring to the value being validated
+    /// itself.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the [`Path`] with a [`Segment::Field`] appended.
+    #[must_use]
+    pub fn field<F>(mut self, field: F) -> Self
+    where
+        F: Into<Cow<'static, str>>,
+    {
+        self.0.push(Segment::Field(field.into()));
+        self
+    }
+
+    /// Returns the [`Path`] with a [`Segment::Index`] appended.
+    #[must_use]
+    pub fn index(mut self, index: usize) -> Self {
+        self.0.push(Segment::Index(index));
+        self
+    }
+
+    /// Returns the [`Path`] with a [`Segment::Key`] appended.
+    #[must_use]
+    pub fn key<K>(mut self, key: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.0.push(Segment::Key(key.into()));
+        self
+    }
+
+    /// Returns a new [`Path`] made up of the segments of this path followed by
+    /// the segments of the other path.
+    #[must_use]
+    pub fn join(&self, other: &Self) -> Self {
+        Self(self.0.iter().chain(other.0.iter()).cloned().collect())
+    }
+
+    /// Returns whether the [`Path`] is empty, i.e. refers to the value being
+    /// validated itself.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the [`Segment`]s making up the [`Path`].
+    #[must_use]
+    pub fn segments(&self) -> &[Segment] {
+        &self.0
+    }
+}
+
+impl Display for Path {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                Segment::Field(field) if i == 0 => write!(f, "{field}")?,
+                Segment::Field(field) => write!(f, ".{field}")?,
+                Segment::Index(index) => write!(f, "[{index}]")?,
+                Segment::Key(key) => {
+                    f.write_str("[\"")?;
+
+                    for c in key.chars() {
+                        if matches!(c, '"' | '\\') {
+                            f.write_char('\\')?;
+                        }
+
+                        f.write_char(c)?;
+                    }
+
+                    f.write_str("\"]")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for Path {
+    fn from(path: &str) -> Self {
+        parse(path)
+    }
+}
+
+impl From<&String> for Path {
+    fn from(path: &String) -> Self {
+        parse(path)
+    }
+}
+
+impl From<String> for Path {
+    fn from(path: String) -> Self {
+        parse(&path)
+    }
+}
+
+impl From<&Path> for Path {
+    fn from(path: &Path) -> Self {
+        path.clone()
+    }
+}
+
+/// A single segment of a [`Path`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Segment {
+    /// A named field, such as a struct field.
+    Field(Cow<'static, str>),
+    /// A numeric index, such as an index into a sequence.
+    Index(usize),
+    /// A key, such as a key in a map.
+    Key(String),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Parse
+
+// Parsing is lenient, as paths are most often given as simple field names.
+// Bracketed segments containing only digits are parsed as indices, quoted
+// bracketed segments are parsed as keys (with `\"` and `\\` escapes), and
+// other bracketed segments are parsed as unquoted keys. An unterminated
+// bracketed segment is treated as part of a field name.
+
+fn parse(path: &str) -> Path {
+    let mut segments = Vec::new();
+    let mut field = String::new();
+    let mut rest = path;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '.' => {
+                push_field(&mut segments, &mut field);
+                rest = &rest[1..];
+            }
+            '[' => {
+                if let Some((segment, remaining)) = parse_bracketed(&rest[1..]) {
+                    push_field(&mut segments, &mut field);
+                    segments.push(segment);
+                    rest = remaining;
+                } else {
+                    field.push_str(rest);
+                    rest = "";
+                }
+            }
+            _ => {
+                field.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    push_field(&mut segments, &mut field);
+
+    Path(segments)
+}
+
+fn parse_bracketed(input: &str) -> Option<(Segment, &str)> {
+    if let Some(quoted) = input.strip_prefix('"') {
+        let mut key = String::new();
+        let mut chars = quoted.char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => key.push(chars.next()?.1),
+                '"' => {
+                    let remaining = quoted[i + 1..].strip_prefix(']')?;
+
+                    return Some((Segment::Key(key), remaining));
+                }
+                _ => key.push(c),
+            }
+        }
+
+        None
+    } else {
+        let (content, remaining) = input.split_once(']')?;
+        let segment = match content.parse() {
+            Ok(index) if content.bytes().all(|b| b.is_ascii_digit()) => Segment::Index(index),
+            _ => Segment::Key(content.to_owned()),
+        };
+
+        Some((segment, remaining))
+    }
+}
+
+fn push_field(segments: &mut Vec<Segment>, field: &mut String) {
+    if !field.is_empty() {
+        segments.push(Segment::Field(Cow::Owned(std::mem::take(field))));
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::path::{
+        Path,
+        Segment,
+    };
+
+    // Build
+
+    #[test]
+    fn path_new_is_empty() {
+        let path = Path::new();
+
+        assert!(path.is_empty());
+        assert_eq!(path.to_string(), "");
+    }
+
+    #[test]
+    fn path_build_segments() {
+        let path = Path::new().field("events").index(3).field("tags").index(0);
+
+        assert_eq!(path.segments(), &[
+            Segment::Field("events".into()),
+            Segment::Index(3),
+            Segment::Field("tags".into()),
+            Segment::Index(0),
+        ]);
+    }
+
+    #[test]
+    fn path_join() {
+        let parent = Path::new().field("events").index(3);
+        let child = Path::new().field("tags").index(0);
+
+        assert_eq!(
+            parent.join(&child),
+            Path::new().field("events").index(3).field("tags").index(0)
+        );
+    }
+
+    // Display
+
+    #[test]
+    fn path_display_fields_and_indices() {
+        let path = Path::new().field("events").index(3).field("tags").index(0);
+
+        assert_eq!(path.to_string(), "events[3].tags[0]");
+    }
+
+    #[test]
+    fn path_display_key() {
+        let path = Path::new().field("metadata").key("x y");
+
+        assert_eq!(path.to_string(), r#"metadata["x y"]"#);
+    }
+
+    #[test]
+    fn path_display_key_escaped() {
+        let path = Path::new().field("metadata").key(r#"a"b"#);
+
+        assert_eq!(path.to_string(), r#"metadata["a\"b"]"#);
+    }
+
+    #[test]
+    fn path_display_leading_index() {
+        let path = Path::new().index(2).field("name");
+
+        assert_eq!(path.to_string(), "[2].name");
+    }
+
+    // Parse
+
+    #[test]
+    fn path_parse_single_field() {
+        assert_eq!(Path::from("number"), Path::new().field("number"));
+    }
+
+    #[test]
+    fn path_parse_fields_and_indices() {
+        assert_eq!(
+            Path::from("events[3].tags[0]"),
+            Path::new().field("events").index(3).field("tags").index(0)
+        );
+    }
+
+    #[test]
+    fn path_parse_quoted_key() {
+        assert_eq!(
+            Path::from(r#"metadata["x y"]"#),
+            Path::new().field("metadata").key("x y")
+        );
+    }
+
+    #[test]
+    fn path_parse_quoted_key_escaped() {
+        assert_eq!(
+            Path::from(r#"metadata["a\"b"]"#),
+            Path::new().field("metadata").key(r#"a"b"#)
+        );
+    }
+
+    #[test]
+    fn path_parse_unquoted_key() {
+        assert_eq!(
+            Path::from("metadata[name]"),
+            Path::new().field("metadata").key("name")
+        );
+    }
+
+    #[test]
+    fn path_parse_unterminated_bracket() {
+        assert_eq!(Path::from("field[3"), Path::new().field("field[3"));
+    }
+
+    #[test]
+    fn path_parse_display_round_trip() {
+        let path = Path::new().field("a").index(1).key("b c").field("d");
+
+        assert_eq!(Path::from(path.to_string()), path);
+    }
+
+    #[test]
+    fn path_parse_display_round_trip_escaped_key() {
+        let path = Path::new().field("a").key("b\n\"c\\\u{1}é");
+
+        assert_eq!(path.to_string(), "a[\"b\n\\\"c\\\\\u{1}é\"]");
+        assert_eq!(Path::from(path.to_string()), path);
+    }
+}
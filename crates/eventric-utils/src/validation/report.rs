@@ -0,0 +1,295 @@
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+};
+use crate::validation::{
+    Failure,
+    Severity,
+    Validator,
+};
+
+// =================================================================================================
+// Report
+// =================================================================================================
+
+/// A collection of advisory [`Failure`]s (those with a [`Severity`] lower than
+/// [`Severity::Error`]) produced during validation, such as warnings about
+/// deprecated or suspicious values, which are returned to the caller alongside
+/// the validation result so that they can be logged or surfaced.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    failures: Vec<Failure>,
+}
+
+impl Report {
+    /// Creates a new empty [`Report`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given failure to the report.
+    pub fn push(&mut self, failure: Failure) {
+        self.failures.push(failure);
+    }
+
+    /// Returns whether the report contains no failures.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Returns the failures of the report, in the order in which they were
+    /// added.
+    #[must_use]
+    pub fn failures(&self) -> &[Failure] {
+        &self.failures
+    }
+
+    /// Returns an iterator over the failures of the report with
+    /// [`Severity::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = &Failure> {
+        self.with_severity(Severity::Warning)
+    }
+
+    /// Returns an iterator over the failures of the report with
+    /// [`Severity::Notice`].
+    pub fn notices(&self) -> impl Iterator<Item = &Failure> {
+        self.with_severity(Severity::Notice)
+    }
+
+    /// Consumes the report, returning its failures.
+    #[must_use]
+    pub fn into_failures(self) -> Vec<Failure> {
+        self.failures
+    }
+
+    fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &Failure> {
+        self.failures
+            .iter()
+            .filter(move |failure| failure.severity() == severity)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Advisory
+
+/// Adapts a [`Validator`] to produce advisory failures of the given
+/// [`Severity`] (usually [`Severity::Warning`] or [`Severity::Notice`]) in
+/// place of errors, so that values which do not meet the criterion of the
+/// validator are flagged without being rejected.
+pub struct Advisory<V> {
+    severity: Severity,
+    validator: V,
+}
+
+impl<V> Advisory<V> {
+    /// Creates a new [`Advisory`] validator, producing failures of the given
+    /// severity.
+    pub fn new(validator: V, severity: Severity) -> Self {
+        Self {
+            severity,
+            validator,
+        }
+    }
+
+    /// Creates a new [`Advisory`] validator, producing failures of
+    /// [`Severity::Warning`].
+    pub fn warning(validator: V) -> Self {
+        Self::new(validator, Severity::Warning)
+    }
+
+    /// Creates a new [`Advisory`] validator, producing failures of
+    /// [`Severity::Notice`].
+    pub fn notice(validator: V) -> Self {
+        Self::new(validator, Severity::Notice)
+    }
+}
+
+impl<T, V> Validator<T> for Advisory<V>
+where
+    T: ?Sized,
+    V: Validator<T>,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        self.validator
+            .validate(value)
+            .map(|failure| failure.with_severity(self.severity))
+    }
+}
+
+/// Advisory failures do not cause validation to fail, so [`Advisory`] adds no
+/// keywords.
+#[cfg(feature = "schema")]
+impl<V> Describe for Advisory<V> {
+    fn describe(&self, _: &mut Schema) {}
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::{
+        assert_err,
+        assert_none,
+        assert_ok,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Error,
+        Failure,
+        Mode,
+        NoWhiteSpace,
+        NotEmpty,
+        Severity,
+        Validator,
+        report::{
+            Advisory,
+            Report,
+        },
+        validate,
+        validate_all,
+        validate_all_reported,
+        validate_reported,
+    };
+
+    // Test Validators
+
+    struct NoUppercase;
+
+    impl Validator<str> for NoUppercase {
+        fn validate(&self, value: &str) -> Option<Failure> {
+            value
+                .contains(char::is_uppercase)
+                .then(|| Failure::new("uppercase", "uppercase"))
+        }
+    }
+
+    // Advisory
+
+    #[test]
+    fn advisory_valid() {
+        assert_none!(Advisory::warning(NoUppercase).validate("tag"));
+    }
+
+    #[test]
+    fn advisory_invalid() {
+        assert_some_eq!(
+            Some(Failure::new("uppercase", "uppercase").with_severity(Severity::Warning)),
+            Advisory::warning(NoUppercase).validate("Tag")
+        );
+        assert_some_eq!(
+            Some(Failure::new("uppercase", "uppercase").with_severity(Severity::Notice)),
+            Advisory::notice(NoUppercase).validate("Tag")
+        );
+    }
+
+    // Validate
+
+    #[test]
+    fn validate_ignores_advisory_failures() {
+        assert_ok!(validate("Tag", "tag", &[
+            &NotEmpty,
+            &Advisory::warning(NoUppercase)
+        ]));
+        assert_ok!(validate_all("Tag", "tag", &[
+            &NotEmpty,
+            &Advisory::warning(NoUppercase)
+        ]));
+    }
+
+    #[test]
+    fn validate_reported_valid() {
+        let mut report = Report::new();
+
+        assert_ok!(validate_reported(
+            "Tag",
+            "tag",
+            &[&NotEmpty, &Advisory::warning(NoUppercase)],
+            &mut report
+        ));
+        assert_eq!(report.failures(), &[Failure::new("uppercase", "uppercase")
+            .with_path("tag")
+            .with_severity(Severity::Warning)]);
+    }
+
+    #[test]
+    fn validate_reported_continues_after_advisory_failure() {
+        let mut report = Report::new();
+
+        assert_eq!(
+            validate_reported(
+                "T ag",
+                "tag",
+                &[&Advisory::notice(NoUppercase), &NoWhiteSpace],
+                &mut report
+            ),
+            Err(Error::Invalid(
+                Failure::new("whitespace", "whitespace").with_path("tag")
+            ))
+        );
+        assert_eq!(report.notices().count(), 1);
+        assert_eq!(report.warnings().count(), 0);
+    }
+
+    #[test]
+    fn validate_all_reported_invalid() {
+        let mut report = Report::new();
+
+        assert_eq!(
+            validate_all_reported(
+                "T ag",
+                "tag",
+                &[&NoWhiteSpace, &Advisory::warning(NoUppercase)],
+                &mut report
+            ),
+            Err(Error::InvalidAll(vec![
+                Failure::new("whitespace", "whitespace").with_path("tag")
+            ]))
+        );
+        assert_eq!(report.warnings().count(), 1);
+    }
+
+    #[test]
+    fn mode_validate_reported() {
+        let mut report = Report::new();
+
+        assert_ok!(Mode::All.validate_reported(
+            "Tag",
+            "tag",
+            &[&Advisory::warning(NoUppercase)],
+            &mut report
+        ));
+        assert_err!(Mode::FailFast.validate_reported("", "tag", &[&NotEmpty], &mut report));
+        assert_eq!(report.into_failures().len(), 1);
+    }
+
+    // Report
+
+    #[test]
+    fn report_severities() {
+        let mut report = Report::new();
+
+        assert!(report.is_empty());
+
+        report.push(Failure::new("a", "a").with_severity(Severity::Warning));
+        report.push(Failure::new("b", "b").with_severity(Severity::Notice));
+        report.push(Failure::new("c", "c").with_severity(Severity::Warning));
+
+        assert!(!report.is_empty());
+        assert_eq!(
+            report.warnings().map(Failure::code).collect::<Vec<_>>(),
+            vec!["a", "c"]
+        );
+        assert_eq!(
+            report.notices().map(Failure::code).collect::<Vec<_>>(),
+            vec!["b"]
+        );
+        assert_eq!(report.failures().len(), 3);
+    }
+}
@@ -0,0 +1,254 @@
+use std::borrow::Cow;
+
+use crate::validation::{
+    Error,
+    Failure,
+    INVALID,
+    Path,
+    Rule,
+};
+
+// =================================================================================================
+// Rule
+// =================================================================================================
+
+impl<T, F> Rule<T> for F
+where
+    T: ?Sized,
+    F: Fn(&T) -> Result<(), Error>,
+{
+    fn check(&self, value: &T) -> Result<(), Error> {
+        self(value)
+    }
+}
+
+/// Adapts a predicate function over a whole value to be a [`Rule`], reporting
+/// a [`Failure`] with the generic `invalid` code and the given message against
+/// each of the named fields when the predicate returns `false`. When more than
+/// one field is named, the result is an [`Error::InvalidAll`] error containing
+/// one failure for each field. When no field is named, the failure is reported
+/// against the value itself.
+pub struct Fields<F> {
+    message: Cow<'static, str>,
+    names: &'static [&'static str],
+    predicate: F,
+}
+
+impl<F> Fields<F> {
+    /// Creates a new [`Fields`] rule from the given field names, predicate
+    /// function, and failure message.
+    pub fn new<M>(fields: &'static [&'static str], predicate: F, message: M) -> Self
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        Self {
+            message: message.into(),
+            names: fields,
+            predicate,
+        }
+    }
+}
+
+impl<T, F> Rule<T> for Fields<F>
+where
+    T: ?Sized,
+    F: Fn(&T) -> bool,
+{
+    fn check(&self, value: &T) -> Result<(), Error> {
+        if (self.predicate)(value) {
+            return Ok(());
+        }
+
+        let mut failures = self
+            .names
+            .iter()
+            .map(|field| Failure::new(INVALID, self.message.clone()).with_path(Path::from(*field)))
+            .collect::<Vec<_>>();
+
+        match failures.len() {
+            0 => Err(Error::Invalid(Failure::new(INVALID, self.message.clone()))),
+            1 => Err(Error::Invalid(failures.remove(0))),
+            _ => Err(Error::InvalidAll(failures)),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use assertables::assert_ok;
+
+    use crate::validation::{
+        Error,
+        Failure,
+        Mode,
+        Rule,
+        rule::Fields,
+        validate_rules,
+    };
+
+    // Test Types
+
+    struct Window {
+        end: u32,
+        start: u32,
+    }
+
+    struct Message {
+        causation_id: Option<String>,
+        correlation_id: Option<String>,
+    }
+
+    fn end_after_start(window: &Window) -> Result<(), Error> {
+        if window.end > window.start {
+            Ok(())
+        } else {
+            Err(Error::Invalid(
+                Failure::new("end_before_start", "must be after start").with_path("end"),
+            ))
+        }
+    }
+
+    fn correlation_required() -> Fields<impl Fn(&Message) -> bool> {
+        Fields::new(
+            &["correlation_id"],
+            |message: &Message| message.causation_id.is_none() || message.correlation_id.is_some(),
+            "required when causation_id is set",
+        )
+    }
+
+    fn window(start: u32, end: u32) -> Window {
+        Window { end, start }
+    }
+
+    // Rule
+
+    #[test]
+    fn rule_function_valid() {
+        assert_ok!(end_after_start.check(&window(1, 2)));
+    }
+
+    #[test]
+    fn rule_function_invalid() {
+        assert_eq!(
+            end_after_start.check(&window(2, 2)),
+            Err(Error::Invalid(
+                Failure::new("end_before_start", "must be after start").with_path("end")
+            ))
+        );
+    }
+
+    // Fields
+
+    #[test]
+    fn fields_single_valid() {
+        let rule = correlation_required();
+
+        assert_ok!(rule.check(&Message {
+            causation_id: None,
+            correlation_id: None,
+        }));
+        assert_ok!(rule.check(&Message {
+            causation_id: Some(String::from("a")),
+            correlation_id: Some(String::from("b")),
+        }));
+    }
+
+    #[test]
+    fn fields_single_invalid() {
+        let rule = correlation_required();
+
+        assert_eq!(
+            rule.check(&Message {
+                causation_id: Some(String::from("a")),
+                correlation_id: None,
+            }),
+            Err(Error::Invalid(
+                Failure::new("invalid", "required when causation_id is set")
+                    .with_path("correlation_id")
+            ))
+        );
+    }
+
+    #[test]
+    fn fields_multiple_invalid() {
+        let rule = Fields::new(
+            &["start", "end"],
+            |window: &Window| window.start < window.end,
+            "start must be before end",
+        );
+
+        assert_eq!(
+            rule.check(&window(3, 1)),
+            Err(Error::InvalidAll(vec![
+                Failure::new("invalid", "start must be before end").with_path("start"),
+                Failure::new("invalid", "start must be before end").with_path("end"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn fields_none_invalid() {
+        let rule = Fields::new(
+            &[],
+            |window: &Window| window.start < window.end,
+            "start must be before end",
+        );
+
+        assert_eq!(
+            rule.check(&window(3, 1)),
+            Err(Error::Invalid(Failure::new(
+                "invalid",
+                "start must be before end"
+            )))
+        );
+    }
+
+    // Validate Rules
+
+    #[test]
+    fn validate_rules_valid() {
+        let rules: &[&dyn Rule<Window>] = &[&end_after_start];
+
+        assert_ok!(validate_rules(&window(1, 2), rules, Mode::FailFast));
+        assert_ok!(validate_rules(&window(1, 2), rules, Mode::All));
+    }
+
+    #[test]
+    fn validate_rules_fail_fast() {
+        let below_100 = Fields::new(
+            &["end"],
+            |window: &Window| window.end < 100,
+            "must be below 100",
+        );
+        let rules: &[&dyn Rule<Window>] = &[&end_after_start, &below_100];
+
+        assert_eq!(
+            validate_rules(&window(200, 100), rules, Mode::FailFast),
+            Err(Error::Invalid(
+                Failure::new("end_before_start", "must be after start").with_path("end")
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_rules_all() {
+        let below_100 = Fields::new(
+            &["end"],
+            |window: &Window| window.end < 100,
+            "must be below 100",
+        );
+        let rules: &[&dyn Rule<Window>] = &[&end_after_start, &below_100];
+
+        assert_eq!(
+            validate_rules(&window(200, 100), rules, Mode::All),
+            Err(Error::InvalidAll(vec![
+                Failure::new("end_before_start", "must be after start").with_path("end"),
+                Failure::new("invalid", "must be below 100").with_path("end"),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(feature = "unicode")]
+use unicode_normalization::UnicodeNormalization as _;
+
+#[cfg(feature = "unicode")]
+use crate::validation::NormalizationForm;
+use crate::validation::Sanitizer;
+
+// =================================================================================================
+// Sanitize
+// =================================================================================================
+
+/// Sanitizes a value by removing preceding and trailing white space.
+pub struct Trim;
+
+impl Sanitizer<String> for Trim {
+    fn sanitize(&self, mut value: String) -> String {
+        value.truncate(value.trim_end().len());
+        value.split_off(value.len() - value.trim_start().len())
+    }
+}
+
+/// Sanitizes a value by replacing each run of white space characters with a
+/// single space. Preceding and trailing white space is collapsed, but not
+/// removed (see [`Trim`]).
+pub struct CollapseWhiteSpace;
+
+impl Sanitizer<String> for CollapseWhiteSpace {
+    fn sanitize(&self, value: String) -> String {
+        let mut collapsed = String::with_capacity(value.len());
+        let mut white_space = false;
+
+        for c in value.chars() {
+            if c.is_whitespace() {
+                if !white_space {
+                    collapsed.push(' ');
+                }
+
+                white_space = true;
+            } else {
+                collapsed.push(c);
+                white_space = false;
+            }
+        }
+
+        collapsed
+    }
+}
+
+/// Sanitizes a value by removing any control characters.
+pub struct StripControlCharacters;
+
+impl Sanitizer<String> for StripControlCharacters {
+    fn sanitize(&self, mut value: String) -> String {
+        value.retain(|c| !c.is_control());
+        value
+    }
+}
+
+/// Sanitizes a value by converting it to lowercase (as defined by the Unicode
+/// `Lowercase` property).
+pub struct Lowercase;
+
+impl Sanitizer<String> for Lowercase {
+    fn sanitize(&self, value: String) -> String {
+        value.to_lowercase()
+    }
+}
+
+/// Sanitizes a value by converting it to the given Unicode normalization form.
+#[cfg(feature = "unicode")]
+#[derive(Clone, Copy, Debug)]
+pub struct Normalize(NormalizationForm);
+
+#[cfg(feature = "unicode")]
+impl Normalize {
+    /// Creates a new [`Normalize`] sanitizer for the given form.
+    #[must_use]
+    pub fn new(form: NormalizationForm) -> Self {
+        Self(form)
+    }
+
+    /// Creates a new [`Normalize`] sanitizer for [`NormalizationForm::Nfc`].
+    #[must_use]
+    pub fn nfc() -> Self {
+        Self(NormalizationForm::Nfc)
+    }
+
+    /// Creates a new [`Normalize`] sanitizer for [`NormalizationForm::Nfkc`].
+    #[must_use]
+    pub fn nfkc() -> Self {
+        Self(NormalizationForm::Nfkc)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl Sanitizer<String> for Normalize {
+    fn sanitize(&self, value: String) -> String {
+        match self.0 {
+            NormalizationForm::Nfc => value.nfc().collect(),
+            NormalizationForm::Nfkc => value.nfkc().collect(),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::{
+        Sanitizer as _,
+        sanitize::{
+            CollapseWhiteSpace,
+            Lowercase,
+            StripControlCharacters,
+            Trim,
+        },
+    };
+
+    // Trim
+
+    #[test]
+    fn trim() {
+        assert_eq!("a b", Trim.sanitize(String::from(" \ta b\n ")));
+        assert_eq!("a b", Trim.sanitize(String::from("a b")));
+        assert_eq!("", Trim.sanitize(String::from("  ")));
+        assert_eq!("", Trim.sanitize(String::new()));
+    }
+
+    // Collapse White Space
+
+    #[test]
+    fn collapse_white_space() {
+        assert_eq!(
+            "a b c",
+            CollapseWhiteSpace.sanitize(String::from("a  b\t\nc"))
+        );
+        assert_eq!(
+            " a b ",
+            CollapseWhiteSpace.sanitize(String::from("\t a \u{a0} b  "))
+        );
+        assert_eq!("ab", CollapseWhiteSpace.sanitize(String::from("ab")));
+    }
+
+    // Strip Control Characters
+
+    #[test]
+    fn strip_control_characters() {
+        assert_eq!(
+            "ab",
+            StripControlCharacters.sanitize(String::from("a\u{0}b\u{7f}"))
+        );
+        assert_eq!(
+            "ab",
+            StripControlCharacters.sanitize(String::from("a\tb\n"))
+        );
+        assert_eq!("a b", StripControlCharacters.sanitize(String::from("a b")));
+    }
+
+    // Lowercase
+
+    #[test]
+    fn lowercase() {
+        assert_eq!(
+            "order.placed",
+            Lowercase.sanitize(String::from("Order.PLACED"))
+        );
+        assert_eq!("straße", Lowercase.sanitize(String::from("STRAßE")));
+    }
+
+    // Normalize
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn normalize() {
+        use crate::validation::sanitize::Normalize;
+
+        assert_eq!(
+            "caf\u{e9}",
+            Normalize::nfc().sanitize(String::from("cafe\u{301}"))
+        );
+        assert_eq!(
+            "\u{fb01}le",
+            Normalize::nfc().sanitize(String::from("\u{fb01}le"))
+        );
+        assert_eq!(
+            "file",
+            Normalize::nfkc().sanitize(String::from("\u{fb01}le"))
+        );
+    }
+}
@@ -0,0 +1,505 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        VecDeque,
+    },
+    rc::Rc,
+    sync::Arc,
+};
+
+use serde_json::{
+    Map,
+    Number,
+    Value as Json,
+};
+
+use crate::validation::{
+    ValidateSchema,
+    Value,
+};
+
+// =================================================================================================
+// Schema
+// =================================================================================================
+
+/// A JSON Schema object, mapping keywords (such as `minLength`) to their
+/// values.
+pub type Schema = Map<String, Json>;
+
+pub(crate) const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl From<Value> for Json {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Bool(value) => Self::Bool(value),
+            Value::Char(value) => Self::String(value.to_string()),
+            Value::Float(value) => Number::from_f64(value).map_or(Self::Null, Self::Number),
+            Value::Integer(value) => Number::from_i128(value).map_or(Self::Null, Self::Number),
+            Value::String(value) => Self::String(value.into_owned()),
+            Value::Unsigned(value) => Number::from_u128(value).map_or(Self::Null, Self::Number),
+        }
+    }
+}
+
+// Wrappers
+
+impl<T> ValidateSchema for &T
+where
+    T: ValidateSchema + ?Sized,
+{
+    fn schema() -> Schema {
+        T::schema()
+    }
+}
+
+impl<T> ValidateSchema for Arc<T>
+where
+    T: ValidateSchema + ?Sized,
+{
+    fn schema() -> Schema {
+        T::schema()
+    }
+}
+
+impl<T> ValidateSchema for Box<T>
+where
+    T: ValidateSchema + ?Sized,
+{
+    fn schema() -> Schema {
+        T::schema()
+    }
+}
+
+impl<T> ValidateSchema for Option<T>
+where
+    T: ValidateSchema,
+{
+    fn schema() -> Schema {
+        let mut null = Schema::new();
+
+        null.insert(String::from("type"), Json::from("null"));
+
+        let mut schema = Schema::new();
+
+        schema.insert(
+            String::from("anyOf"),
+            Json::Array(vec![Json::Object(T::schema()), Json::Object(null)]),
+        );
+
+        schema
+    }
+}
+
+impl<T> ValidateSchema for Rc<T>
+where
+    T: ValidateSchema + ?Sized,
+{
+    fn schema() -> Schema {
+        T::schema()
+    }
+}
+
+// Sequences
+
+impl<T, const N: usize> ValidateSchema for [T; N]
+where
+    T: ValidateSchema,
+{
+    fn schema() -> Schema {
+        let mut schema = array::<T>();
+
+        schema.insert(String::from("minItems"), Json::from(N));
+        schema.insert(String::from("maxItems"), Json::from(N));
+        schema
+    }
+}
+
+impl<T> ValidateSchema for [T]
+where
+    T: ValidateSchema,
+{
+    fn schema() -> Schema {
+        array::<T>()
+    }
+}
+
+impl<T> ValidateSchema for Vec<T>
+where
+    T: ValidateSchema,
+{
+    fn schema() -> Schema {
+        array::<T>()
+    }
+}
+
+impl<T> ValidateSchema for VecDeque<T>
+where
+    T: ValidateSchema,
+{
+    fn schema() -> Schema {
+        array::<T>()
+    }
+}
+
+// Maps
+
+impl<K, V> ValidateSchema for BTreeMap<K, V>
+where
+    V: ValidateSchema,
+{
+    fn schema() -> Schema {
+        object::<V>()
+    }
+}
+
+impl<K, V, S> ValidateSchema for HashMap<K, V, S>
+where
+    V: ValidateSchema,
+{
+    fn schema() -> Schema {
+        object::<V>()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Functions
+
+/// Adds the given keyword to the given schema. Where the keyword is already
+/// present with a different value, it is added as a further `allOf` subschema
+/// instead, so that both values apply.
+pub(crate) fn keyword<V>(schema: &mut Schema, name: &str, value: V)
+where
+    V: Into<Json>,
+{
+    let value = value.into();
+
+    match schema.get(name) {
+        None => {
+            schema.insert(name.to_owned(), value);
+        }
+        Some(existing) if *existing == value => {}
+        Some(_) => {
+            let mut subschema = Schema::new();
+
+            subschema.insert(name.to_owned(), value);
+
+            if let Json::Array(all) = schema
+                .entry("allOf")
+                .or_insert_with(|| Json::Array(Vec::new()))
+            {
+                all.push(Json::Object(subschema));
+            }
+        }
+    }
+}
+
+fn array<T>() -> Schema
+where
+    T: ValidateSchema,
+{
+    let mut schema = Schema::new();
+
+    schema.insert(String::from("type"), Json::from("array"));
+    schema.insert(String::from("items"), Json::Object(T::schema()));
+    schema
+}
+
+fn object<V>() -> Schema
+where
+    V: ValidateSchema,
+{
+    let mut schema = Schema::new();
+
+    schema.insert(String::from("type"), Json::from("object"));
+    schema.insert(
+        String::from("additionalProperties"),
+        Json::Object(V::schema()),
+    );
+    schema
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::validation::{
+        Advisory,
+        CharacterSet,
+        Characters,
+        Describe,
+        Each,
+        ItemsRange,
+        Keys,
+        LengthRange,
+        LengthUnit,
+        Max,
+        MaxItems,
+        MaxLength,
+        Min,
+        MinItems,
+        MinLength,
+        MultipleOf,
+        NoControlCharacters,
+        NoPrecedingWhiteSpace,
+        NoTrailingWhiteSpace,
+        NoWhiteSpace,
+        NonNegative,
+        NonZero,
+        NotEmpty,
+        Optional,
+        Positive,
+        Range,
+        Unique,
+        ValidateSchema,
+        Value,
+        describe,
+        describe_nested,
+        describe_object,
+        schema::{
+            Schema,
+            keyword,
+        },
+        schema_document,
+    };
+
+    // Test Types
+
+    struct Tag;
+
+    impl ValidateSchema for Tag {
+        fn schema() -> Schema {
+            describe_object([("name", describe(&[&NotEmpty, &NoWhiteSpace]))])
+        }
+    }
+
+    fn json(schema: Schema) -> serde_json::Value {
+        serde_json::Value::Object(schema)
+    }
+
+    // Keyword
+
+    #[test]
+    fn keyword_conflicting() {
+        let mut schema = Schema::new();
+
+        keyword(&mut schema, "pattern", "a");
+        keyword(&mut schema, "pattern", "a");
+        keyword(&mut schema, "pattern", "b");
+
+        assert_eq!(
+            json(schema),
+            json!({ "pattern": "a", "allOf": [{ "pattern": "b" }] })
+        );
+    }
+
+    // Value
+
+    #[test]
+    fn value_into_json() {
+        assert_eq!(serde_json::Value::from(Value::from(3_u8)), json!(3));
+        assert_eq!(serde_json::Value::from(Value::from(-3_i64)), json!(-3));
+        assert_eq!(serde_json::Value::from(Value::from(0.5_f64)), json!(0.5));
+        assert_eq!(serde_json::Value::from(Value::from(f64::NAN)), json!(null));
+        assert_eq!(serde_json::Value::from(Value::from('a')), json!("a"));
+        assert_eq!(serde_json::Value::from(Value::from(true)), json!(true));
+    }
+
+    // Strings
+
+    #[test]
+    fn describe_strings() {
+        assert_eq!(
+            json(describe(&[&NotEmpty])),
+            json!({ "minLength": 1, "minItems": 1, "minProperties": 1 })
+        );
+        assert_eq!(
+            json(describe(&[&NoWhiteSpace])),
+            json!({ "pattern": r"^\S*$" })
+        );
+        assert_eq!(
+            json(describe(&[&NoPrecedingWhiteSpace, &NoTrailingWhiteSpace])),
+            json!({ "pattern": r"^(?!\s)", "allOf": [{ "pattern": r"(?<!\s)$" }] })
+        );
+        assert_eq!(
+            json(describe(&[&NoControlCharacters])),
+            json!({ "pattern": r"^[^\u0000-\u001F\u007F-\u009F]*$" })
+        );
+    }
+
+    #[test]
+    fn describe_lengths() {
+        assert_eq!(
+            json(describe(&[&MinLength::new(2), &MaxLength::new(8)])),
+            json!({
+                "minLength": 2,
+                "minItems": 2,
+                "minProperties": 2,
+                "maxLength": 8,
+                "maxItems": 8,
+                "maxProperties": 8,
+            })
+        );
+        assert_eq!(
+            json(describe(&[&LengthRange::new(2, 8)])),
+            json!({
+                "minLength": 2,
+                "maxLength": 8,
+                "minItems": 2,
+                "maxItems": 8,
+                "minProperties": 2,
+                "maxProperties": 8,
+            })
+        );
+        assert_eq!(
+            json(describe(&[&MaxLength::new(8).with_unit(LengthUnit::Bytes)])),
+            json!({ "maxItems": 8, "maxProperties": 8 })
+        );
+    }
+
+    #[test]
+    fn describe_characters() {
+        assert_eq!(
+            json(describe(&[&Characters::hex()])),
+            json!({ "pattern": "^[0-9A-Fa-f]*$" })
+        );
+        assert_eq!(
+            json(describe(&[&Characters::deny(CharacterSet::Set(
+                "-]^".into()
+            ))])),
+            json!({ "pattern": r"^[^\-\]\^]*$" })
+        );
+        assert_eq!(
+            json(describe(&[&Characters::ascii()])),
+            json!({ "pattern": r"^[\u0000-\u007F]*$" })
+        );
+        assert_eq!(
+            json(describe(&[&Characters::allow(CharacterSet::Set(
+                "é😀".into()
+            ))])),
+            json!({ "pattern": r"^[\u00E9\uD83D\uDE00]*$" })
+        );
+        assert_eq!(json(describe(&[&Characters::printable()])), json!({}));
+    }
+
+    // Numbers
+
+    #[test]
+    fn describe_numbers() {
+        assert_eq!(
+            json(describe(&[
+                &Min::new(1),
+                &Max::new(10),
+                &MultipleOf::new(2)
+            ])),
+            json!({ "minimum": 1, "maximum": 10, "multipleOf": 2 })
+        );
+        assert_eq!(json(describe(&[&MultipleOf::new(0)])), json!({}));
+        assert_eq!(json(describe(&[&MultipleOf::new(-2.5)])), json!({}));
+        assert_eq!(
+            json(describe(&[&Range::new(0.5, 1.5)])),
+            json!({ "minimum": 0.5, "maximum": 1.5 })
+        );
+        assert_eq!(
+            json(describe(&[&Positive])),
+            json!({ "exclusiveMinimum": 0 })
+        );
+        assert_eq!(json(describe(&[&NonNegative])), json!({ "minimum": 0 }));
+        assert_eq!(
+            json(describe(&[&NonZero])),
+            json!({ "not": { "const": 0 } })
+        );
+    }
+
+    // Collections
+
+    #[test]
+    fn describe_collections() {
+        assert_eq!(
+            json(describe(&[&MinItems::new(1), &MaxItems::new(4), &Unique])),
+            json!({
+                "minItems": 1,
+                "minProperties": 1,
+                "maxItems": 4,
+                "maxProperties": 4,
+                "uniqueItems": true
+            })
+        );
+        assert_eq!(
+            json(describe(&[&ItemsRange::new(1, 4)])),
+            json!({ "minItems": 1, "minProperties": 1, "maxItems": 4, "maxProperties": 4 })
+        );
+        assert_eq!(
+            json(describe(&[
+                &Each::new(MaxLength::new(8)),
+                &Keys::new(NoWhiteSpace)
+            ])),
+            json!({
+                "items": { "maxLength": 8, "maxItems": 8, "maxProperties": 8 },
+                "additionalProperties": { "maxLength": 8, "maxItems": 8, "maxProperties": 8 },
+                "propertyNames": { "pattern": r"^\S*$" }
+            })
+        );
+    }
+
+    // Adapters
+
+    #[test]
+    fn describe_adapters() {
+        assert_eq!(
+            json(describe(&[&Optional::new(MinLength::new(2))])),
+            json!({ "minLength": 2, "minItems": 2, "minProperties": 2 })
+        );
+        assert_eq!(json(describe(&[&Advisory::warning(NotEmpty)])), json!({}));
+    }
+
+    // Validate Schema
+
+    #[test]
+    fn describe_nested_schema() {
+        assert_eq!(
+            json(describe_nested::<Vec<Tag>>(&[&MinItems::new(1)])),
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "minLength": 1, "minItems": 1, "minProperties": 1, "pattern": r"^\S*$" }
+                    }
+                },
+                "minItems": 1,
+                "minProperties": 1
+            })
+        );
+        assert_eq!(
+            json(<Option<Box<Tag>>>::schema()),
+            json!({ "anyOf": [Tag::schema(), { "type": "null" }] })
+        );
+        assert_eq!(json(<[Tag; 2]>::schema())["maxItems"], json!(2));
+    }
+
+    #[test]
+    fn schema_document_dialect() {
+        let document = schema_document::<Tag>();
+
+        assert_eq!(
+            document["$schema"],
+            json!("https://json-schema.org/draft/2020-12/schema")
+        );
+        assert_eq!(document["type"], json!("object"));
+    }
+
+    // Describe
+
+    #[test]
+    fn describe_trait_object() {
+        let validators: [&dyn Describe; 2] = [&NotEmpty, &MaxLength::new(3)];
+
+        assert_eq!(json(describe(&validators))["maxLength"], json!(3));
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+use unicode_general_category::{
+    GeneralCategory,
+    get_general_category,
+};
+use unicode_normalization::{
+    is_nfc,
+    is_nfkc,
+};
+use unicode_security::{
+    MixedScript as _,
+    is_potential_mixed_script_confusable_char,
+};
+
+#[cfg(feature = "schema")]
+use crate::validation::{
+    Describe,
+    Schema,
+    schema::keyword,
+};
+use crate::validation::{
+    Failure,
+    Validator,
+    forward::{
+        forward_strings,
+        forward_wrappers,
+    },
+};
+
+// =================================================================================================
+// Unicode
+// =================================================================================================
+
+/// A Unicode normalization form, for use with the [`Normalized`] validator.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NormalizationForm {
+    /// Normalization Form C (canonical composition).
+    Nfc,
+    /// Normalization Form KC (compatibility composition).
+    Nfkc,
+}
+
+impl Display for NormalizationForm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nfc => write!(f, "NFC"),
+            Self::Nfkc => write!(f, "NFKC"),
+        }
+    }
+}
+
+/// Validates that a value is in the given Unicode normalization form, so that
+/// values which look the same are also equal.
+#[derive(Clone, Copy, Debug)]
+pub struct Normalized(NormalizationForm);
+
+impl Normalized {
+    /// Creates a new [`Normalized`] validator for the given form.
+    #[must_use]
+    pub fn new(form: NormalizationForm) -> Self {
+        Self(form)
+    }
+
+    /// Creates a new [`Normalized`] validator for [`NormalizationForm::Nfc`].
+    #[must_use]
+    pub fn nfc() -> Self {
+        Self(NormalizationForm::Nfc)
+    }
+
+    /// Creates a new [`Normalized`] validator for [`NormalizationForm::Nfkc`].
+    #[must_use]
+    pub fn nfkc() -> Self {
+        Self(NormalizationForm::Nfkc)
+    }
+}
+
+impl<T> Validator<T> for Normalized
+where
+    T: UnicodeValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        let value = value.unicode_validation();
+        let normalized = match self.0 {
+            NormalizationForm::Nfc => is_nfc(&value),
+            NormalizationForm::Nfkc => is_nfkc(&value),
+        };
+
+        (!normalized).then(|| {
+            Failure::new("not_normalized", format!("not in {} form", self.0))
+                .with_param("form", self.0.to_string())
+        })
+    }
+}
+
+/// Validates that a value does not contain format characters (Unicode general
+/// category `Cf`), which includes invisible characters such as zero-width
+/// joiners and bidirectional overrides. The first format character and its
+/// byte offset are given as parameters of the failure.
+pub struct NoFormatCharacters;
+
+impl<T> Validator<T> for NoFormatCharacters
+where
+    T: UnicodeValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        value
+            .unicode_validation()
+            .char_indices()
+            .find(|(_, c)| get_general_category(*c) == GeneralCategory::Format)
+            .map(|(offset, character)| {
+                Failure::new("format_characters", "format characters")
+                    .with_param("character", character)
+                    .with_param("offset", offset)
+            })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl Describe for NoFormatCharacters {
+    fn describe(&self, schema: &mut Schema) {
+        keyword(schema, "pattern", r"^\P{Cf}*$");
+    }
+}
+
+/// Validates that a value is single-script, as defined by Unicode Technical
+/// Standard #39 (characters common to many scripts, such as digits and
+/// punctuation, are compatible with any script).
+pub struct NoMixedScripts;
+
+impl<T> Validator<T> for NoMixedScripts
+where
+    T: UnicodeValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        (!value.unicode_validation().is_single_script())
+            .then(|| Failure::new("mixed_scripts", "mixed scripts"))
+    }
+}
+
+/// Validates that a value does not contain non-ASCII characters which are
+/// potentially confusable with characters of another script (for example
+/// Cyrillic `а`, which is confusable with Latin `a`), as defined by Unicode
+/// Technical Standard #39. The first confusable character and its byte offset
+/// are given as parameters of the failure.
+pub struct NoConfusables;
+
+impl<T> Validator<T> for NoConfusables
+where
+    T: UnicodeValidation + ?Sized,
+{
+    fn validate(&self, value: &T) -> Option<Failure> {
+        value
+            .unicode_validation()
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii() && is_potential_mixed_script_confusable_char(*c))
+            .map(|(offset, character)| {
+                Failure::new("confusable_characters", "confusable characters")
+                    .with_param("character", character)
+                    .with_param("offset", offset)
+            })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Supporting Trait
+
+/// Supports validation by the Unicode validators ([`Normalized`],
+/// [`NoFormatCharacters`], [`NoMixedScripts`], and [`NoConfusables`]), which
+/// all inspect the value as a string.
+pub trait UnicodeValidation {
+    /// Returns the value as a string, converting lossily where the value may
+    /// not be valid UTF-8.
+    fn unicode_validation(&self) -> Cow<'_, str>;
+}
+
+impl UnicodeValidation for OsStr {
+    fn unicode_validation(&self) -> Cow<'_, str> {
+        self.to_string_lossy()
+    }
+}
+
+impl UnicodeValidation for str {
+    fn unicode_validation(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+forward_strings!(UnicodeValidation {
+    fn unicode_validation(&self) -> Cow<'_, str>;
+});
+
+forward_wrappers!(UnicodeValidation {
+    fn unicode_validation(&self) -> Cow<'_, str>;
+});
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ffi::OsString,
+        sync::Arc,
+    };
+
+    use assertables::{
+        assert_none,
+        assert_some_eq,
+    };
+
+    use crate::validation::{
+        Failure,
+        Validator as _,
+        unicode::{
+            NoConfusables,
+            NoFormatCharacters,
+            NoMixedScripts,
+            NormalizationForm,
+            Normalized,
+        },
+    };
+
+    // Normalized
+
+    #[test]
+    fn normalized_nfc_valid() {
+        assert_none!(Normalized::nfc().validate("caf\u{e9}"));
+        assert_none!(Normalized::nfc().validate("order.placed"));
+    }
+
+    #[test]
+    fn normalized_nfc_invalid() {
+        assert_some_eq!(
+            Some(Failure::new("not_normalized", "not in NFC form").with_param("form", "NFC")),
+            Normalized::nfc().validate("cafe\u{301}")
+        );
+    }
+
+    #[test]
+    fn normalized_nfkc_valid() {
+        assert_none!(Normalized::new(NormalizationForm::Nfkc).validate("file"));
+    }
+
+    #[test]
+    fn normalized_nfkc_invalid() {
+        assert_none!(Normalized::nfc().validate("\u{fb01}le"));
+        assert_some_eq!(
+            Some(Failure::new("not_normalized", "not in NFKC form").with_param("form", "NFKC")),
+            Normalized::nfkc().validate("\u{fb01}le")
+        );
+    }
+
+    // No Format Characters
+
+    #[test]
+    fn no_format_characters_valid() {
+        assert_none!(NoFormatCharacters.validate("order.placed"));
+        assert_none!(NoFormatCharacters.validate("caf\u{e9}"));
+    }
+
+    #[test]
+    fn no_format_characters_invalid_zero_width_joiner() {
+        assert_some_eq!(
+            Some(
+                Failure::new("format_characters", "format characters")
+                    .with_param("character", '\u{200d}')
+                    .with_param("offset", 5_usize)
+            ),
+            NoFormatCharacters.validate("order\u{200d}.placed")
+        );
+    }
+
+    #[test]
+    fn no_format_characters_invalid_bidi_override() {
+        assert_some_eq!(
+            Some(
+                Failure::new("format_characters", "format characters")
+                    .with_param("character", '\u{202e}')
+                    .with_param("offset", 0_usize)
+            ),
+            NoFormatCharacters.validate("\u{202e}txt.exe")
+        );
+    }
+
+    // No Mixed Scripts
+
+    #[test]
+    fn no_mixed_scripts_valid() {
+        assert_none!(NoMixedScripts.validate("order.placed-2"));
+        assert_none!(NoMixedScripts.validate("\u{437}\u{430}\u{43a}\u{430}\u{437}"));
+    }
+
+    #[test]
+    fn no_mixed_scripts_invalid() {
+        assert_some_eq!(
+            Some(Failure::new("mixed_scripts", "mixed scripts")),
+            NoMixedScripts.validate("p\u{430}ypal")
+        );
+    }
+
+    // No Confusables
+
+    #[test]
+    fn no_confusables_valid() {
+        assert_none!(NoConfusables.validate("paypal"));
+        assert_none!(NoConfusables.validate("caf\u{e9}"));
+    }
+
+    #[test]
+    fn no_confusables_invalid() {
+        assert_some_eq!(
+            Some(
+                Failure::new("confusable_characters", "confusable characters")
+                    .with_param("character", '\u{430}')
+                    .with_param("offset", 1_usize)
+            ),
+            NoConfusables.validate("p\u{430}ypal")
+        );
+    }
+
+    // Other Types
+
+    #[test]
+    fn unicode_arc_str() {
+        let value: Arc<str> = Arc::from("cafe\u{301}");
+
+        assert_some_eq!(
+            Some("not in NFC form"),
+            Normalized::nfc()
+                .validate(&value)
+                .as_ref()
+                .map(Failure::message)
+        );
+    }
+
+    #[test]
+    fn unicode_osstring() {
+        let value = OsString::from("a\u{200b}b");
+
+        assert_some_eq!(
+            Some("format characters"),
+            NoFormatCharacters
+                .validate(&value)
+                .as_ref()
+                .map(Failure::message)
+        );
+    }
+}
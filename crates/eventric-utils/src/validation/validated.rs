@@ -0,0 +1,282 @@
+use std::{
+    borrow::Borrow,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    ops::Deref,
+};
+
+#[cfg(feature = "serde")]
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    de,
+};
+
+use crate::validation::Validate;
+
+// =================================================================================================
+// Validated
+// =================================================================================================
+
+/// A value which has been proven valid by its [`Validate`] implementation. A
+/// [`Validated`] value can only be constructed by successful validation, so
+/// APIs which require valid input can accept a `Validated<T>` rather than a
+/// `T` and rely on validation having happened. The value dereferences to the
+/// inner value, but cannot be mutated, as mutation could invalidate it.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Validated<T>(T);
+
+impl<T> Validated<T>
+where
+    T: Validate,
+{
+    /// Validates the given value, returning a [`Validated`] value if valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error returned by the [`Validate`] implementation of the
+    /// value when validation fails.
+    pub fn new(value: T) -> Result<Self, T::Err> {
+        value.validate().map(Self)
+    }
+}
+
+impl<T> Validated<T> {
+    /// Consumes the [`Validated`] value, returning the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Validated<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Borrow<T> for Validated<T> {
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Display for Validated<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Serde
+
+// With the `serde` feature enabled, a `Validated<T>` serializes transparently
+// as the inner value, and deserializes by deserializing the inner value and
+// then validating it, so that invalid input fails deserialization with the
+// validation error as the message.
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for Validated<T>
+where
+    T: Deserialize<'de> + Validate,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::new(T::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Serialize for Validated<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    use std::collections::BTreeMap;
+
+    use assertables::{
+        assert_err,
+        assert_ok,
+    };
+    #[cfg(feature = "serde")]
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de,
+    };
+
+    use crate::validation::{
+        Error,
+        Failure,
+        NoWhiteSpace,
+        NotEmpty,
+        Validate,
+        validate,
+        validated::Validated,
+    };
+
+    // Test Types
+
+    #[derive(Debug, PartialEq)]
+    struct Name(String);
+
+    #[cfg(feature = "serde")]
+    impl<'de> Deserialize<'de> for Name {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            String::deserialize(deserializer).map(Self)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl Serialize for Name {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl Name {
+        fn as_str(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl Validate for Name {
+        type Err = Error;
+
+        fn validate(self) -> Result<Self, Self::Err> {
+            validate(&self.0, "name", &[&NotEmpty, &NoWhiteSpace])?;
+
+            Ok(self)
+        }
+    }
+
+    fn greet(name: &Validated<Name>) -> String {
+        format!("Hello {}", name.as_str())
+    }
+
+    // Validated
+
+    #[test]
+    fn validated_new_valid() {
+        let name = assert_ok!(Validated::new(Name(String::from("World"))));
+
+        assert_eq!(greet(&name), "Hello World");
+    }
+
+    #[test]
+    fn validated_new_invalid() {
+        let error = assert_err!(Validated::new(Name(String::from("Big World"))));
+
+        assert_eq!(
+            error,
+            Error::Invalid(Failure::new("whitespace", "whitespace").with_path("name"))
+        );
+    }
+
+    #[test]
+    fn validated_deref() {
+        let name = assert_ok!(Validated::new(Name(String::from("World"))));
+
+        assert_eq!(*name, Name(String::from("World")));
+        assert_eq!(name.as_str().len(), 5);
+    }
+
+    #[test]
+    fn validated_into_inner() {
+        let name = assert_ok!(Validated::new(Name(String::from("World"))));
+
+        assert_eq!(name.into_inner(), Name(String::from("World")));
+    }
+
+    // Serde
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn validated_deserialize_valid() {
+        let name: Validated<Name> = assert_ok!(serde_json::from_str(r#""World""#));
+
+        assert_eq!(name.as_str(), "World");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn validated_deserialize_invalid() {
+        let error = assert_err!(serde_json::from_str::<Validated<Name>>(r#""Big World""#));
+
+        assert_eq!(error.to_string(), "Validation Error: name: whitespace");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn validated_deserialize_nested() {
+        #[derive(Debug)]
+        struct Event {
+            name: Validated<Name>,
+        }
+
+        impl<'de> Deserialize<'de> for Event {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                BTreeMap::<String, Validated<Name>>::deserialize(deserializer)?
+                    .remove("name")
+                    .map(|name| Self { name })
+                    .ok_or_else(|| de::Error::missing_field("name"))
+            }
+        }
+
+        let event: Event = assert_ok!(serde_json::from_str(r#"{ "name": "created" }"#));
+
+        assert_eq!(event.name.as_str(), "created");
+        assert_err!(serde_json::from_str::<Event>(r#"{ "name": "" }"#));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn validated_serialize_transparent() {
+        let name = assert_ok!(Validated::new(Name(String::from("World"))));
+
+        assert_eq!(assert_ok!(serde_json::to_string(&name)), r#""World""#);
+    }
+}